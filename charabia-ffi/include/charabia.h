@@ -0,0 +1,148 @@
+/* charabia C API, generated by cbindgen from charabia-ffi/src/lib.rs. */
+
+#ifndef CHARABIA_H
+#define CHARABIA_H
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+// Status returned by the functions of the API.
+typedef enum CharabiaStatus {
+  // the function succeeded.
+  CHARABIA_STATUS_OK = 0,
+  // a required pointer is null.
+  CHARABIA_STATUS_NULL_POINTER = 1,
+  // a text or a string of the configuration is not valid UTF-8.
+  CHARABIA_STATUS_INVALID_UTF8 = 2,
+  // a language of the allow list is not a known ISO 639-3 code.
+  CHARABIA_STATUS_UNKNOWN_LANGUAGE = 3,
+  // the stop words can't be loaded.
+  CHARABIA_STATUS_INVALID_STOP_WORDS = 4,
+  // charabia panicked, this is a bug.
+  CHARABIA_STATUS_PANIC = 5,
+} CharabiaStatus;
+
+// Kind of a [`CharabiaToken`].
+typedef enum CharabiaTokenKind {
+  CHARABIA_TOKEN_KIND_UNKNOWN = 0,
+  CHARABIA_TOKEN_KIND_WORD = 1,
+  CHARABIA_TOKEN_KIND_STOP_WORD = 2,
+  CHARABIA_TOKEN_KIND_SEPARATOR_HARD = 3,
+  CHARABIA_TOKEN_KIND_SEPARATOR_SOFT = 4,
+} CharabiaTokenKind;
+
+// A tokenizer created by [`charabia_tokenizer_new`], must be freed with [`charabia_tokenizer_free`].
+//
+// A tokenizer can be used by several threads at the same time.
+typedef struct CharabiaTokenizer CharabiaTokenizer;
+
+// Owned storage of a [`CharabiaTokens`].
+typedef struct CharabiaTokensStorage CharabiaTokensStorage;
+
+// Configuration of a [`CharabiaTokenizer`], see `TokenizerBuilder` for the meaning of each option.
+//
+// Each list is an array of NUL-terminated UTF-8 strings and its length,
+// a null array uses the default of charabia.
+// The configuration is copied by [`charabia_tokenizer_new`] and can be freed afterward.
+typedef struct CharabiaConfig {
+  // words classified as stop words.
+  const char *const *stop_words;
+  size_t stop_words_len;
+  // separators replacing the default ones.
+  const char *const *separators;
+  size_t separators_len;
+  // words segmented before any other segmentation.
+  const char *const *words_dict;
+  size_t words_dict_len;
+  // ISO 639-3 codes of the languages allowed during the detection, like `"eng"`.
+  const char *const *allow_list;
+  size_t allow_list_len;
+  // enable the lossy normalization, like the lowercasing or the removal of diacritics.
+  bool lossy;
+} CharabiaConfig;
+
+// A token produced by [`charabia_tokenize`].
+//
+// The strings are owned by the [`CharabiaTokens`] containing the token.
+typedef struct CharabiaToken {
+  // normalized lemma, NUL-terminated but it may contain NUL characters, use `lemma_len`.
+  const char *lemma;
+  // length of the lemma in bytes, without the terminating NUL.
+  size_t lemma_len;
+  // byte offsets of the token in the original text.
+  size_t byte_start;
+  size_t byte_end;
+  // character offsets of the token in the original text.
+  size_t char_start;
+  size_t char_end;
+  enum CharabiaTokenKind kind;
+  // name of the script, like `"Latin"`, NUL-terminated and static.
+  const char *script;
+  // ISO 639-3 code of the language, NUL-terminated and static, or null if no language is detected.
+  const char *language;
+} CharabiaToken;
+
+// Array of tokens produced by [`charabia_tokenize`], must be freed with [`charabia_tokens_free`].
+typedef struct CharabiaTokens {
+  const struct CharabiaToken *tokens;
+  size_t len;
+  // storage of the tokens and their lemmas, private.
+  struct CharabiaTokensStorage *storage;
+} CharabiaTokens;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+// Returns a configuration using the defaults of charabia.
+struct CharabiaConfig charabia_config_default(void);
+
+// Creates a tokenizer from a configuration, writing it in `out`.
+//
+// # Safety
+//
+// `config` must point to a valid [`CharabiaConfig`] whose arrays contain the given number of valid C strings,
+// and `out` must be a valid pointer.
+enum CharabiaStatus charabia_tokenizer_new(const struct CharabiaConfig *config,
+                                           struct CharabiaTokenizer **out);
+
+// Frees a tokenizer created by [`charabia_tokenizer_new`], does nothing if `tokenizer` is null.
+//
+// # Safety
+//
+// `tokenizer` must come from [`charabia_tokenizer_new`] and must not be used afterward.
+void charabia_tokenizer_free(struct CharabiaTokenizer *tokenizer);
+
+// Tokenizes a UTF-8 text of `len` bytes, writing the tokens in `out`.
+//
+// The text is not required to be NUL-terminated and can be freed once the function returns.
+// On error, `out` is set to an empty array that can still be passed to [`charabia_tokens_free`].
+//
+// # Safety
+//
+// `tokenizer` must come from [`charabia_tokenizer_new`], `text` must point to `len` readable bytes
+// and `out` must be a valid pointer.
+enum CharabiaStatus charabia_tokenize(const struct CharabiaTokenizer *tokenizer,
+                                      const uint8_t *text,
+                                      size_t len,
+                                      struct CharabiaTokens *out);
+
+// Frees the tokens written by [`charabia_tokenize`] and resets `tokens` to an empty array,
+// does nothing if `tokens` is null.
+//
+// # Safety
+//
+// `tokens` must have been written by [`charabia_tokenize`] and the lemmas must not be used afterward.
+void charabia_tokens_free(struct CharabiaTokens *tokens);
+
+// Returns a human readable description of a status, NUL-terminated and static.
+const char *charabia_status_message(enum CharabiaStatus status);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* CHARABIA_H */
@@ -0,0 +1,475 @@
+//! C API over charabia.
+//!
+//! This crate exposes the [`charabia::Tokenizer`] to C and to any language able to call C functions,
+//! producing exactly the same tokens as the Rust API.
+//! The header of the API is `include/charabia.h`, it is generated by cbindgen from this file.
+//!
+//! Example
+//! --------
+//! ```c
+//! CharabiaConfig config = charabia_config_default();
+//! CharabiaTokenizer *tokenizer = NULL;
+//! charabia_tokenizer_new(&config, &tokenizer);
+//!
+//! const char *text = "The quick brown fox";
+//! CharabiaTokens tokens;
+//! charabia_tokenize(tokenizer, (const uint8_t *)text, strlen(text), &tokens);
+//! for (size_t i = 0; i < tokens.len; i++) {
+//!     printf("%s\n", tokens.tokens[i].lemma);
+//! }
+//!
+//! charabia_tokens_free(&tokens);
+//! charabia_tokenizer_free(tokenizer);
+//! ```
+
+use std::collections::{BTreeSet, HashMap};
+use std::ffi::{c_char, CStr, CString};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::sync::{LazyLock, Mutex};
+use std::{ptr, slice};
+
+use charabia::{Language, SeparatorKind, Token, TokenKind};
+use fst::Set;
+
+pub use crate::owned::{OwnedTokenizer, TokenizerConfig};
+
+mod owned;
+
+/// Status returned by the functions of the API.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CharabiaStatus {
+    /// the function succeeded.
+    Ok = 0,
+    /// a required pointer is null.
+    NullPointer = 1,
+    /// a text or a string of the configuration is not valid UTF-8.
+    InvalidUtf8 = 2,
+    /// a language of the allow list is not a known ISO 639-3 code.
+    UnknownLanguage = 3,
+    /// the stop words can't be loaded.
+    InvalidStopWords = 4,
+    /// charabia panicked, this is a bug.
+    Panic = 5,
+}
+
+/// Kind of a [`CharabiaToken`].
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CharabiaTokenKind {
+    Unknown = 0,
+    Word = 1,
+    StopWord = 2,
+    SeparatorHard = 3,
+    SeparatorSoft = 4,
+}
+
+impl From<TokenKind> for CharabiaTokenKind {
+    fn from(kind: TokenKind) -> Self {
+        match kind {
+            TokenKind::Word => CharabiaTokenKind::Word,
+            TokenKind::StopWord => CharabiaTokenKind::StopWord,
+            TokenKind::Separator(SeparatorKind::Hard) => CharabiaTokenKind::SeparatorHard,
+            TokenKind::Separator(SeparatorKind::Soft) => CharabiaTokenKind::SeparatorSoft,
+            TokenKind::Unknown => CharabiaTokenKind::Unknown,
+        }
+    }
+}
+
+/// Configuration of a [`CharabiaTokenizer`], see `TokenizerBuilder` for the meaning of each option.
+///
+/// Each list is an array of NUL-terminated UTF-8 strings and its length,
+/// a null array uses the default of charabia.
+/// The configuration is copied by [`charabia_tokenizer_new`] and can be freed afterward.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct CharabiaConfig {
+    /// words classified as stop words.
+    pub stop_words: *const *const c_char,
+    pub stop_words_len: usize,
+    /// separators replacing the default ones.
+    pub separators: *const *const c_char,
+    pub separators_len: usize,
+    /// words segmented before any other segmentation.
+    pub words_dict: *const *const c_char,
+    pub words_dict_len: usize,
+    /// ISO 639-3 codes of the languages allowed during the detection, like `"eng"`.
+    pub allow_list: *const *const c_char,
+    pub allow_list_len: usize,
+    /// enable the lossy normalization, like the lowercasing or the removal of diacritics.
+    pub lossy: bool,
+}
+
+/// A token produced by [`charabia_tokenize`].
+///
+/// The strings are owned by the [`CharabiaTokens`] containing the token.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct CharabiaToken {
+    /// normalized lemma, NUL-terminated but it may contain NUL characters, use `lemma_len`.
+    pub lemma: *const c_char,
+    /// length of the lemma in bytes, without the terminating NUL.
+    pub lemma_len: usize,
+    /// byte offsets of the token in the original text.
+    pub byte_start: usize,
+    pub byte_end: usize,
+    /// character offsets of the token in the original text.
+    pub char_start: usize,
+    pub char_end: usize,
+    pub kind: CharabiaTokenKind,
+    /// name of the script, like `"Latin"`, NUL-terminated and static.
+    pub script: *const c_char,
+    /// ISO 639-3 code of the language, NUL-terminated and static, or null if no language is detected.
+    pub language: *const c_char,
+}
+
+/// Array of tokens produced by [`charabia_tokenize`], must be freed with [`charabia_tokens_free`].
+#[repr(C)]
+#[derive(Debug)]
+pub struct CharabiaTokens {
+    pub tokens: *const CharabiaToken,
+    pub len: usize,
+    /// storage of the tokens and their lemmas, private.
+    pub storage: *mut CharabiaTokensStorage,
+}
+
+/// Owned storage of a [`CharabiaTokens`].
+pub struct CharabiaTokensStorage {
+    tokens: Vec<CharabiaToken>,
+    _lemmas: Vec<u8>,
+}
+
+/// A tokenizer created by [`charabia_tokenizer_new`], must be freed with [`charabia_tokenizer_free`].
+///
+/// A tokenizer can be used by several threads at the same time.
+pub struct CharabiaTokenizer {
+    inner: OwnedTokenizer,
+}
+
+/// Returns a configuration using the defaults of charabia.
+#[no_mangle]
+pub extern "C" fn charabia_config_default() -> CharabiaConfig {
+    CharabiaConfig {
+        stop_words: ptr::null(),
+        stop_words_len: 0,
+        separators: ptr::null(),
+        separators_len: 0,
+        words_dict: ptr::null(),
+        words_dict_len: 0,
+        allow_list: ptr::null(),
+        allow_list_len: 0,
+        lossy: true,
+    }
+}
+
+/// Creates a tokenizer from a configuration, writing it in `out`.
+///
+/// # Safety
+///
+/// `config` must point to a valid [`CharabiaConfig`] whose arrays contain the given number of valid C strings,
+/// and `out` must be a valid pointer.
+#[no_mangle]
+pub unsafe extern "C" fn charabia_tokenizer_new(
+    config: *const CharabiaConfig,
+    out: *mut *mut CharabiaTokenizer,
+) -> CharabiaStatus {
+    if config.is_null() || out.is_null() {
+        return CharabiaStatus::NullPointer;
+    }
+
+    let config = *config;
+    catch_panic(|| {
+        let config = match read_config(&config) {
+            Ok(config) => config,
+            Err(status) => return status,
+        };
+
+        *out = Box::into_raw(Box::new(CharabiaTokenizer { inner: OwnedTokenizer::new(config) }));
+        CharabiaStatus::Ok
+    })
+}
+
+/// Frees a tokenizer created by [`charabia_tokenizer_new`], does nothing if `tokenizer` is null.
+///
+/// # Safety
+///
+/// `tokenizer` must come from [`charabia_tokenizer_new`] and must not be used afterward.
+#[no_mangle]
+pub unsafe extern "C" fn charabia_tokenizer_free(tokenizer: *mut CharabiaTokenizer) {
+    if !tokenizer.is_null() {
+        drop(Box::from_raw(tokenizer));
+    }
+}
+
+/// Tokenizes a UTF-8 text of `len` bytes, writing the tokens in `out`.
+///
+/// The text is not required to be NUL-terminated and can be freed once the function returns.
+/// On error, `out` is set to an empty array that can still be passed to [`charabia_tokens_free`].
+///
+/// # Safety
+///
+/// `tokenizer` must come from [`charabia_tokenizer_new`], `text` must point to `len` readable bytes
+/// and `out` must be a valid pointer.
+#[no_mangle]
+pub unsafe extern "C" fn charabia_tokenize(
+    tokenizer: *const CharabiaTokenizer,
+    text: *const u8,
+    len: usize,
+    out: *mut CharabiaTokens,
+) -> CharabiaStatus {
+    if out.is_null() {
+        return CharabiaStatus::NullPointer;
+    }
+    *out = CharabiaTokens { tokens: ptr::null(), len: 0, storage: ptr::null_mut() };
+
+    if tokenizer.is_null() || (text.is_null() && len != 0) {
+        return CharabiaStatus::NullPointer;
+    }
+
+    let bytes = if len == 0 { &[][..] } else { slice::from_raw_parts(text, len) };
+    let Ok(text) = std::str::from_utf8(bytes) else {
+        return CharabiaStatus::InvalidUtf8;
+    };
+
+    let tokenizer = &*tokenizer;
+    catch_panic(|| {
+        let tokens: Vec<_> = tokenizer.inner.tokenizer().tokenize(text).collect();
+        *out = into_records(&tokens);
+        CharabiaStatus::Ok
+    })
+}
+
+/// Frees the tokens written by [`charabia_tokenize`] and resets `tokens` to an empty array,
+/// does nothing if `tokens` is null.
+///
+/// # Safety
+///
+/// `tokens` must have been written by [`charabia_tokenize`] and the lemmas must not be used afterward.
+#[no_mangle]
+pub unsafe extern "C" fn charabia_tokens_free(tokens: *mut CharabiaTokens) {
+    let Some(tokens) = tokens.as_mut() else { return };
+    if !tokens.storage.is_null() {
+        drop(Box::from_raw(tokens.storage));
+    }
+    *tokens = CharabiaTokens { tokens: ptr::null(), len: 0, storage: ptr::null_mut() };
+}
+
+/// Returns a human readable description of a status, NUL-terminated and static.
+#[no_mangle]
+pub extern "C" fn charabia_status_message(status: CharabiaStatus) -> *const c_char {
+    let message: &'static CStr = match status {
+        CharabiaStatus::Ok => c"ok",
+        CharabiaStatus::NullPointer => c"a required pointer is null",
+        CharabiaStatus::InvalidUtf8 => c"a string is not valid UTF-8",
+        CharabiaStatus::UnknownLanguage => c"unknown ISO 639-3 language code",
+        CharabiaStatus::InvalidStopWords => c"the stop words can't be loaded",
+        CharabiaStatus::Panic => c"charabia panicked",
+    };
+    message.as_ptr()
+}
+
+fn catch_panic(f: impl FnOnce() -> CharabiaStatus) -> CharabiaStatus {
+    catch_unwind(AssertUnwindSafe(f)).unwrap_or(CharabiaStatus::Panic)
+}
+
+unsafe fn read_config(config: &CharabiaConfig) -> Result<TokenizerConfig, CharabiaStatus> {
+    let stop_words = match read_strings(config.stop_words, config.stop_words_len)? {
+        Some(words) => {
+            // an fst Set must be built from sorted and deduplicated words.
+            let words: BTreeSet<_> = words.into_iter().collect();
+            Some(Set::from_iter(words).map_err(|_| CharabiaStatus::InvalidStopWords)?)
+        }
+        None => None,
+    };
+
+    let allow_list = read_strings(config.allow_list, config.allow_list_len)?
+        .unwrap_or_default()
+        .iter()
+        .map(|code| Language::from_code(code).ok_or(CharabiaStatus::UnknownLanguage))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // an empty array keeps the default of charabia, like a null one.
+    Ok(TokenizerConfig {
+        stop_words,
+        separators: read_strings(config.separators, config.separators_len)?
+            .filter(|separators| !separators.is_empty()),
+        words_dict: read_strings(config.words_dict, config.words_dict_len)?
+            .filter(|words| !words.is_empty()),
+        allow_list: Some(allow_list).filter(|languages| !languages.is_empty()),
+        lossy: config.lossy,
+        ..TokenizerConfig::default()
+    })
+}
+
+/// Reads an array of C strings, returns `None` if the array is null.
+unsafe fn read_strings(
+    strings: *const *const c_char,
+    len: usize,
+) -> Result<Option<Vec<String>>, CharabiaStatus> {
+    if strings.is_null() {
+        return Ok(None);
+    }
+
+    slice::from_raw_parts(strings, len)
+        .iter()
+        .map(|s| {
+            if s.is_null() {
+                return Err(CharabiaStatus::NullPointer);
+            }
+            let s = CStr::from_ptr(*s).to_str().map_err(|_| CharabiaStatus::InvalidUtf8)?;
+            Ok(s.to_string())
+        })
+        .collect::<Result<_, _>>()
+        .map(Some)
+}
+
+/// Converts the tokens into C records, the lemmas are stored NUL-terminated in a single buffer.
+fn into_records(tokens: &[Token]) -> CharabiaTokens {
+    let mut lemmas = Vec::with_capacity(tokens.iter().map(|t| t.byte_len() + 1).sum());
+    for token in tokens {
+        lemmas.extend_from_slice(token.lemma().as_bytes());
+        lemmas.push(0);
+    }
+
+    let mut offset = 0;
+    let records = tokens
+        .iter()
+        .map(|token| {
+            let lemma = lemmas[offset..].as_ptr() as *const c_char;
+            offset += token.byte_len() + 1;
+            CharabiaToken {
+                lemma,
+                lemma_len: token.byte_len(),
+                byte_start: token.byte_start,
+                byte_end: token.byte_end,
+                char_start: token.char_start,
+                char_end: token.char_end,
+                kind: token.kind.into(),
+                script: static_c_str(token.script.name()),
+                language: token.language.map_or(ptr::null(), |l| static_c_str(l.code())),
+            }
+        })
+        .collect();
+
+    let storage = Box::new(CharabiaTokensStorage { tokens: records, _lemmas: lemmas });
+    let tokens = storage.tokens.as_ptr();
+    let len = storage.tokens.len();
+    CharabiaTokens { tokens, len, storage: Box::into_raw(storage) }
+}
+
+/// Returns a static NUL-terminated copy of a script name or a language code.
+fn static_c_str(s: &'static str) -> *const c_char {
+    // the names and codes are a small finite set, each one is allocated once and never freed.
+    static CACHE: LazyLock<Mutex<HashMap<&'static str, &'static CStr>>> =
+        LazyLock::new(Default::default);
+
+    let mut cache = CACHE.lock().unwrap_or_else(|e| e.into_inner());
+    let c_str = cache
+        .entry(s)
+        .or_insert_with(|| Box::leak(CString::new(s).unwrap_or_default().into_boxed_c_str()));
+    c_str.as_ptr()
+}
+
+#[cfg(test)]
+mod test {
+    use charabia::TokenizerBuilder;
+
+    use super::*;
+
+    unsafe fn tokenize(config: &CharabiaConfig, text: &str) -> Vec<(String, CharabiaTokenKind)> {
+        let mut tokenizer = ptr::null_mut();
+        assert_eq!(charabia_tokenizer_new(config, &mut tokenizer), CharabiaStatus::Ok);
+
+        let mut tokens = CharabiaTokens { tokens: ptr::null(), len: 0, storage: ptr::null_mut() };
+        let status = charabia_tokenize(tokenizer, text.as_ptr(), text.len(), &mut tokens);
+        assert_eq!(status, CharabiaStatus::Ok);
+
+        let output = slice::from_raw_parts(tokens.tokens, tokens.len)
+            .iter()
+            .map(|token| {
+                let lemma = slice::from_raw_parts(token.lemma as *const u8, token.lemma_len);
+                (String::from_utf8(lemma.to_vec()).unwrap(), token.kind)
+            })
+            .collect();
+
+        charabia_tokens_free(&mut tokens);
+        charabia_tokenizer_free(tokenizer);
+        output
+    }
+
+    #[test]
+    fn same_tokens_as_rust() {
+        let text = "The quick (\"brown\") fox can't jump 32.3 feet, right? 東京\0end";
+        let expected: Vec<_> = TokenizerBuilder::default()
+            .build()
+            .tokenize(text)
+            .map(|t| (t.lemma().to_string(), t.kind.into()))
+            .collect();
+
+        let output = unsafe { tokenize(&charabia_config_default(), text) };
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn configuration() {
+        let stop_words = [c"the".as_ptr(), c"a".as_ptr()];
+        let separators = [c" ".as_ptr()];
+        let allow_list = [c"eng".as_ptr()];
+        let config = CharabiaConfig {
+            stop_words: stop_words.as_ptr(),
+            stop_words_len: stop_words.len(),
+            separators: separators.as_ptr(),
+            separators_len: separators.len(),
+            allow_list: allow_list.as_ptr(),
+            allow_list_len: allow_list.len(),
+            lossy: false,
+            ..charabia_config_default()
+        };
+
+        let output = unsafe { tokenize(&config, "the Fox-Trot") };
+        assert_eq!(
+            output,
+            [
+                ("the".to_string(), CharabiaTokenKind::StopWord),
+                (" ".to_string(), CharabiaTokenKind::SeparatorSoft),
+                ("Fox-Trot".to_string(), CharabiaTokenKind::Word),
+            ]
+        );
+    }
+
+    #[test]
+    fn errors() {
+        let allow_list = [c"xyz".as_ptr()];
+        let config = CharabiaConfig {
+            allow_list: allow_list.as_ptr(),
+            allow_list_len: allow_list.len(),
+            ..charabia_config_default()
+        };
+        let mut tokenizer = ptr::null_mut();
+        unsafe {
+            assert_eq!(
+                charabia_tokenizer_new(&config, &mut tokenizer),
+                CharabiaStatus::UnknownLanguage
+            );
+            assert!(tokenizer.is_null());
+
+            let config = charabia_config_default();
+            assert_eq!(charabia_tokenizer_new(&config, &mut tokenizer), CharabiaStatus::Ok);
+
+            let mut tokens =
+                CharabiaTokens { tokens: ptr::null(), len: 0, storage: ptr::null_mut() };
+            let invalid = b"w\xFFrld";
+            let status = charabia_tokenize(tokenizer, invalid.as_ptr(), invalid.len(), &mut tokens);
+            assert_eq!(status, CharabiaStatus::InvalidUtf8);
+            assert_eq!(tokens.len, 0);
+            charabia_tokens_free(&mut tokens);
+
+            let status = charabia_tokenize(tokenizer, ptr::null(), 0, &mut tokens);
+            assert_eq!(status, CharabiaStatus::Ok);
+            assert_eq!(tokens.len, 0);
+            charabia_tokens_free(&mut tokens);
+
+            charabia_tokenizer_free(tokenizer);
+        }
+    }
+}
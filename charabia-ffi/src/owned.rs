@@ -0,0 +1,117 @@
+//! A [`Tokenizer`] owning the configuration it borrows.
+//!
+//! The bindings can't keep the lifetime of a [`Tokenizer`] borrowing its configuration,
+//! this module is the only place building such a self-referential tokenizer,
+//! it is also used by the Python bindings.
+
+use std::mem::ManuallyDrop;
+
+use charabia::{Language, Tokenizer, TokenizerBuilder};
+use fst::Set;
+
+/// Owned options of an [`OwnedTokenizer`], see [`TokenizerBuilder`] for the meaning of each option.
+///
+/// A `None` option keeps the default of charabia.
+#[derive(Debug)]
+pub struct TokenizerConfig {
+    pub stop_words: Option<Set<Vec<u8>>>,
+    pub separators: Option<Vec<String>>,
+    pub words_dict: Option<Vec<String>>,
+    pub allow_list: Option<Vec<Language>>,
+    pub lossy: bool,
+    pub create_char_map: bool,
+}
+
+impl Default for TokenizerConfig {
+    fn default() -> Self {
+        TokenizerConfig {
+            stop_words: None,
+            separators: None,
+            words_dict: None,
+            allow_list: None,
+            lossy: true,
+            create_char_map: false,
+        }
+    }
+}
+
+/// The slices of strings borrowed by the [`Tokenizer`].
+struct ConfigRefs<'a> {
+    separators: Option<Vec<&'a str>>,
+    words_dict: Option<Vec<&'a str>>,
+}
+
+/// A [`Tokenizer`] built from a [`TokenizerConfig`] it owns.
+///
+/// A tokenizer can be used by several threads at the same time.
+pub struct OwnedTokenizer {
+    // borrows `refs` and `config`, it must be dropped before them.
+    tokenizer: ManuallyDrop<Tokenizer<'static>>,
+    refs: *mut ConfigRefs<'static>,
+    config: *mut TokenizerConfig,
+}
+
+// SAFETY: the raw pointers are only owned storage that is never mutated after the creation,
+// the borrowed `Set`, `String`s and `Language`s are `Send` and `Sync`.
+unsafe impl Send for OwnedTokenizer {}
+unsafe impl Sync for OwnedTokenizer {}
+
+impl OwnedTokenizer {
+    pub fn new(config: TokenizerConfig) -> Self {
+        let config = Box::into_raw(Box::new(config));
+        // SAFETY: the config is only freed when the tokenizer is dropped, after the tokenizer itself,
+        // and it is never moved because it is boxed.
+        let config_ref: &'static TokenizerConfig = unsafe { &*config };
+
+        let refs = Box::into_raw(Box::new(ConfigRefs {
+            separators: config_ref
+                .separators
+                .as_ref()
+                .map(|separators| separators.iter().map(String::as_str).collect()),
+            words_dict: config_ref
+                .words_dict
+                .as_ref()
+                .map(|words| words.iter().map(String::as_str).collect()),
+        }));
+        // SAFETY: same as the config.
+        let refs_ref: &'static ConfigRefs<'static> = unsafe { &*refs };
+
+        let mut builder = TokenizerBuilder::new();
+        if let Some(stop_words) = &config_ref.stop_words {
+            builder.stop_words(stop_words);
+        }
+        if let Some(separators) = &refs_ref.separators {
+            builder.separators(separators);
+        }
+        if let Some(words_dict) = &refs_ref.words_dict {
+            builder.words_dict(words_dict);
+        }
+        if let Some(allow_list) = &config_ref.allow_list {
+            builder.allow_list(allow_list);
+        }
+        builder.lossy_normalization(config_ref.lossy);
+        builder.create_char_map(config_ref.create_char_map);
+
+        Self { tokenizer: ManuallyDrop::new(builder.into_tokenizer()), refs, config }
+    }
+
+    /// The tokenizer, borrowed from `self` which owns its configuration.
+    ///
+    /// `'static` is only nominal, `Tokenizer` is invariant over its lifetime,
+    /// but it doesn't give access to the borrowed configuration.
+    pub fn tokenizer(&self) -> &Tokenizer<'static> {
+        &self.tokenizer
+    }
+}
+
+impl Drop for OwnedTokenizer {
+    fn drop(&mut self) {
+        // SAFETY: the tokenizer is dropped once and before the storage it borrows,
+        // the pointers come from `Box::into_raw` in `OwnedTokenizer::new`.
+        unsafe {
+            ManuallyDrop::drop(&mut self.tokenizer);
+            drop(Box::from_raw(self.refs));
+            drop(Box::from_raw(self.config));
+        }
+    }
+}
@@ -0,0 +1,137 @@
+/* Tests of the charabia C API, run them with `make -C charabia-ffi/tests/c test`. */
+
+#include <stdio.h>
+#include <string.h>
+
+#include "charabia.h"
+
+static int failures = 0;
+
+#define CHECK(condition)                                                   \
+  do {                                                                     \
+    if (!(condition)) {                                                    \
+      fprintf(stderr, "%s:%d: check failed: %s\n", __FILE__, __LINE__,    \
+              #condition);                                                 \
+      failures++;                                                          \
+    }                                                                      \
+  } while (0)
+
+static int lemma_eq(const CharabiaToken *token, const char *expected) {
+  return token->lemma_len == strlen(expected) &&
+         memcmp(token->lemma, expected, token->lemma_len) == 0;
+}
+
+static CharabiaTokenizer *new_tokenizer(const CharabiaConfig *config) {
+  CharabiaTokenizer *tokenizer = NULL;
+  CharabiaStatus status = charabia_tokenizer_new(config, &tokenizer);
+  if (status != CHARABIA_STATUS_OK) {
+    fprintf(stderr, "charabia_tokenizer_new: %s\n",
+            charabia_status_message(status));
+  }
+  return tokenizer;
+}
+
+static void test_default_config(void) {
+  CharabiaConfig config = charabia_config_default();
+  CharabiaTokenizer *tokenizer = new_tokenizer(&config);
+  CHECK(tokenizer != NULL);
+
+  const char *text = "The quick brown Fox, Été!";
+  CharabiaTokens tokens;
+  CHECK(charabia_tokenize(tokenizer, (const uint8_t *)text, strlen(text),
+                          &tokens) == CHARABIA_STATUS_OK);
+  CHECK(tokens.len == 10);
+
+  CHECK(lemma_eq(&tokens.tokens[0], "the"));
+  CHECK(strcmp(tokens.tokens[0].lemma, "the") == 0);
+  CHECK(tokens.tokens[0].kind == CHARABIA_TOKEN_KIND_WORD);
+  CHECK(strcmp(tokens.tokens[0].script, "Latin") == 0);
+  CHECK(tokens.tokens[1].kind == CHARABIA_TOKEN_KIND_SEPARATOR_SOFT);
+  CHECK(lemma_eq(&tokens.tokens[6], "fox"));
+  CHECK(tokens.tokens[7].kind == CHARABIA_TOKEN_KIND_SEPARATOR_HARD);
+
+  /* `Été` is 5 bytes but 3 characters long. */
+  const CharabiaToken *ete = &tokens.tokens[8];
+  CHECK(lemma_eq(ete, "ete"));
+  CHECK(ete->byte_start == 21 && ete->byte_end == 26);
+  CHECK(ete->char_start == 21 && ete->char_end == 24);
+
+  charabia_tokens_free(&tokens);
+  CHECK(tokens.tokens == NULL && tokens.len == 0);
+  charabia_tokenizer_free(tokenizer);
+}
+
+static void test_custom_config(void) {
+  const char *stop_words[] = {"the", "a"};
+  const char *separators[] = {" ", ", "};
+  const char *words_dict[] = {"New York"};
+  const char *allow_list[] = {"eng", "fra"};
+
+  CharabiaConfig config = charabia_config_default();
+  config.stop_words = stop_words;
+  config.stop_words_len = 2;
+  config.separators = separators;
+  config.separators_len = 2;
+  config.words_dict = words_dict;
+  config.words_dict_len = 1;
+  config.allow_list = allow_list;
+  config.allow_list_len = 2;
+  config.lossy = false;
+
+  CharabiaTokenizer *tokenizer = new_tokenizer(&config);
+  CHECK(tokenizer != NULL);
+
+  const char *text = "the New York-Times";
+  CharabiaTokens tokens;
+  CHECK(charabia_tokenize(tokenizer, (const uint8_t *)text, strlen(text),
+                          &tokens) == CHARABIA_STATUS_OK);
+  CHECK(tokens.len == 4);
+  CHECK(tokens.tokens[0].kind == CHARABIA_TOKEN_KIND_STOP_WORD);
+  CHECK(lemma_eq(&tokens.tokens[2], "New York"));
+  CHECK(lemma_eq(&tokens.tokens[3], "-Times"));
+
+  charabia_tokens_free(&tokens);
+  charabia_tokenizer_free(tokenizer);
+}
+
+static void test_errors(void) {
+  const char *allow_list[] = {"not a language"};
+  CharabiaConfig config = charabia_config_default();
+  config.allow_list = allow_list;
+  config.allow_list_len = 1;
+
+  CharabiaTokenizer *tokenizer = NULL;
+  CHECK(charabia_tokenizer_new(&config, &tokenizer) ==
+        CHARABIA_STATUS_UNKNOWN_LANGUAGE);
+  CHECK(tokenizer == NULL);
+  CHECK(charabia_tokenizer_new(NULL, &tokenizer) ==
+        CHARABIA_STATUS_NULL_POINTER);
+
+  config = charabia_config_default();
+  tokenizer = new_tokenizer(&config);
+
+  const uint8_t invalid[] = {'w', 0xFF, 'r', 'l', 'd'};
+  CharabiaTokens tokens;
+  CHECK(charabia_tokenize(tokenizer, invalid, sizeof(invalid), &tokens) ==
+        CHARABIA_STATUS_INVALID_UTF8);
+  CHECK(tokens.len == 0);
+  charabia_tokens_free(&tokens);
+
+  /* freeing null pointers is a no-op. */
+  charabia_tokens_free(NULL);
+  charabia_tokenizer_free(NULL);
+  charabia_tokenizer_free(tokenizer);
+}
+
+int main(void) {
+  test_default_config();
+  test_custom_config();
+  test_errors();
+
+  if (failures != 0) {
+    fprintf(stderr, "%d check(s) failed\n", failures);
+    return 1;
+  }
+  printf("all C API tests passed\n");
+  return 0;
+}
@@ -0,0 +1,275 @@
+//! Python bindings of charabia.
+//!
+//! The module is built with maturin and exposes the [`charabia::Tokenizer`] and its builder,
+//! producing exactly the same tokens as the Rust API.
+//!
+//! ```python
+//! import charabia
+//!
+//! tokenizer = charabia.TokenizerBuilder().stop_words(["the"]).build()
+//! for token in tokenizer.tokenize("The quick brown fox"):
+//!     print(token.lemma, token.kind, token.char_start, token.char_end)
+//! ```
+
+use std::collections::BTreeSet;
+
+use charabia::{Language, SeparatorKind, StrDetection, TokenKind};
+use charabia_ffi::{OwnedTokenizer, TokenizerConfig};
+use fst::Set;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+/// A token produced by `Tokenizer.tokenize`.
+///
+/// The offsets are given in bytes of the UTF-8 text and in characters,
+/// the character offsets can be used to slice the original Python string.
+#[pyclass(frozen, get_all, skip_from_py_object, module = "charabia")]
+#[derive(Debug, Clone)]
+struct Token {
+    /// the normalized lemma.
+    lemma: String,
+    /// `"word"`, `"stop_word"`, `"separator"` or `"unknown"`.
+    kind: &'static str,
+    /// `"hard"` or `"soft"` for a separator, `None` otherwise.
+    separator_kind: Option<&'static str>,
+    /// name of the script, like `"Latin"`.
+    script: &'static str,
+    /// ISO 639-3 code of the language, like `"eng"`, or `None` if no language is detected.
+    language: Option<&'static str>,
+    char_start: usize,
+    char_end: usize,
+    byte_start: usize,
+    byte_end: usize,
+    /// number of bytes of each original character and of its normalized form, if created.
+    char_map: Option<Vec<(u8, u8)>>,
+}
+
+#[pymethods]
+impl Token {
+    fn is_word(&self) -> bool {
+        self.kind == "word"
+    }
+
+    fn is_stopword(&self) -> bool {
+        self.kind == "stop_word"
+    }
+
+    fn is_separator(&self) -> bool {
+        self.kind == "separator"
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "Token(lemma={:?}, kind={:?}, script={:?}, language={:?}, char_start={}, char_end={})",
+            self.lemma, self.kind, self.script, self.language, self.char_start, self.char_end,
+        )
+    }
+
+    fn __eq__(&self, other: &Self) -> bool {
+        self.lemma == other.lemma
+            && self.kind == other.kind
+            && self.separator_kind == other.separator_kind
+            && self.script == other.script
+            && self.language == other.language
+            && (self.char_start, self.char_end) == (other.char_start, other.char_end)
+            && (self.byte_start, self.byte_end) == (other.byte_start, other.byte_end)
+            && self.char_map == other.char_map
+    }
+}
+
+impl From<charabia::Token<'_>> for Token {
+    fn from(token: charabia::Token) -> Self {
+        let (kind, separator_kind) = match token.kind {
+            TokenKind::Word => ("word", None),
+            TokenKind::StopWord => ("stop_word", None),
+            TokenKind::Separator(SeparatorKind::Hard) => ("separator", Some("hard")),
+            TokenKind::Separator(SeparatorKind::Soft) => ("separator", Some("soft")),
+            TokenKind::Unknown => ("unknown", None),
+        };
+
+        Token {
+            kind,
+            separator_kind,
+            script: token.script.name(),
+            language: token.language.map(|language| language.code()),
+            char_start: token.char_start,
+            char_end: token.char_end,
+            byte_start: token.byte_start,
+            byte_end: token.byte_end,
+            char_map: token.char_map,
+            lemma: token.lemma.into_owned(),
+        }
+    }
+}
+
+/// Options of a `Tokenizer`, see `charabia::TokenizerBuilder` for the meaning of each option.
+#[derive(Debug, Clone)]
+struct Config {
+    stop_words: Option<Vec<String>>,
+    separators: Option<Vec<String>>,
+    words_dict: Option<Vec<String>>,
+    allow_list: Option<Vec<Language>>,
+    lossy: bool,
+    create_char_map: bool,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            stop_words: None,
+            separators: None,
+            words_dict: None,
+            allow_list: None,
+            lossy: true,
+            create_char_map: false,
+        }
+    }
+}
+
+/// Builder of a `Tokenizer`, each method returns the builder to chain the calls.
+#[pyclass(module = "charabia")]
+#[derive(Debug, Default)]
+struct TokenizerBuilder {
+    config: Config,
+}
+
+#[pymethods]
+impl TokenizerBuilder {
+    #[new]
+    fn new() -> Self {
+        Self::default()
+    }
+
+    /// Words classified as stop words.
+    fn stop_words(mut slf: PyRefMut<'_, Self>, stop_words: Vec<String>) -> PyRefMut<'_, Self> {
+        slf.config.stop_words = Some(stop_words);
+        slf
+    }
+
+    /// Separators replacing the default ones.
+    fn separators(mut slf: PyRefMut<'_, Self>, separators: Vec<String>) -> PyRefMut<'_, Self> {
+        slf.config.separators = Some(separators);
+        slf
+    }
+
+    /// Words segmented before any other segmentation.
+    fn words_dict(mut slf: PyRefMut<'_, Self>, words: Vec<String>) -> PyRefMut<'_, Self> {
+        slf.config.words_dict = Some(words);
+        slf
+    }
+
+    /// ISO 639-3 codes of the languages allowed during the detection, like `["eng", "fra"]`.
+    fn allow_list(
+        mut slf: PyRefMut<'_, Self>,
+        languages: Vec<String>,
+    ) -> PyResult<PyRefMut<'_, Self>> {
+        slf.config.allow_list = Some(parse_languages(&languages)?);
+        Ok(slf)
+    }
+
+    /// Enable or disable the lossy normalization, like the lowercasing or the removal of diacritics.
+    fn lossy_normalization(mut slf: PyRefMut<'_, Self>, lossy: bool) -> PyRefMut<'_, Self> {
+        slf.config.lossy = lossy;
+        slf
+    }
+
+    /// Enable or disable the creation of the `char_map` of the tokens.
+    fn create_char_map(mut slf: PyRefMut<'_, Self>, create_char_map: bool) -> PyRefMut<'_, Self> {
+        slf.config.create_char_map = create_char_map;
+        slf
+    }
+
+    /// Builds the configured `Tokenizer`, the builder can be reused afterward.
+    fn build(&self) -> PyResult<Tokenizer> {
+        Tokenizer::from_config(self.config.clone())
+    }
+}
+
+/// Tokenizer segmenting, normalizing and classifying texts like Meilisearch.
+///
+/// `Tokenizer()` uses the default options, use `TokenizerBuilder` to customize them.
+/// A tokenizer can be shared between threads.
+#[pyclass(frozen, module = "charabia")]
+struct Tokenizer {
+    inner: OwnedTokenizer,
+}
+
+impl Tokenizer {
+    fn from_config(config: Config) -> PyResult<Self> {
+        let stop_words = match config.stop_words {
+            Some(words) => {
+                // an fst Set must be built from sorted and deduplicated words.
+                let words: BTreeSet<_> = words.into_iter().collect();
+                Some(Set::from_iter(words).map_err(|e| PyValueError::new_err(e.to_string()))?)
+            }
+            None => None,
+        };
+
+        Ok(Self {
+            inner: OwnedTokenizer::new(TokenizerConfig {
+                stop_words,
+                separators: config.separators,
+                words_dict: config.words_dict,
+                allow_list: config.allow_list,
+                lossy: config.lossy,
+                create_char_map: config.create_char_map,
+            }),
+        })
+    }
+}
+
+#[pymethods]
+impl Tokenizer {
+    #[new]
+    fn new() -> PyResult<Self> {
+        Self::from_config(Config::default())
+    }
+
+    /// Segments, normalizes and classifies the text, returning a list of `Token`.
+    fn tokenize(&self, py: Python<'_>, text: &str) -> Vec<Token> {
+        py.detach(|| self.inner.tokenizer().tokenize(text).map(Token::from).collect())
+    }
+
+    /// Segments the text, returning the list of its segments.
+    fn segment(&self, py: Python<'_>, text: &str) -> Vec<String> {
+        py.detach(|| self.inner.tokenizer().segment_str(text).map(str::to_string).collect())
+    }
+}
+
+/// Returns the ISO 639-3 code of the language of the text, or `None` if it can't be detected.
+///
+/// The detection can be restricted to the languages of `allow_list`.
+#[pyfunction]
+#[pyo3(signature = (text, allow_list=None))]
+fn detect_language(text: &str, allow_list: Option<Vec<String>>) -> PyResult<Option<&'static str>> {
+    let allow_list = allow_list.map(|languages| parse_languages(&languages)).transpose()?;
+    let mut detection = StrDetection::new(text, allow_list.as_deref());
+    Ok(detection.language().map(|language| language.code()))
+}
+
+/// Returns the name of the script of the text, like `"Latin"`, or `"other"` if it can't be detected.
+#[pyfunction]
+fn detect_script(text: &str) -> &'static str {
+    StrDetection::new(text, None).script().name()
+}
+
+fn parse_languages(codes: &[String]) -> PyResult<Vec<Language>> {
+    codes
+        .iter()
+        .map(|code| {
+            Language::from_code(code)
+                .ok_or_else(|| PyValueError::new_err(format!("unknown language code `{code}`")))
+        })
+        .collect()
+}
+
+#[pymodule]
+#[pyo3(name = "charabia")]
+fn charabia_py(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<Token>()?;
+    m.add_class::<Tokenizer>()?;
+    m.add_class::<TokenizerBuilder>()?;
+    m.add_function(wrap_pyfunction!(detect_language, m)?)?;
+    m.add_function(wrap_pyfunction!(detect_script, m)?)?;
+    Ok(())
+}
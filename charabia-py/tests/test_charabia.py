@@ -0,0 +1,113 @@
+"""Tests of the charabia Python bindings, run them with `pytest` after `maturin develop`."""
+
+import threading
+
+import pytest
+
+import charabia
+
+
+def lemmas(tokens):
+    return [token.lemma for token in tokens]
+
+
+def test_default_tokenizer():
+    tokens = charabia.Tokenizer().tokenize("The quick (\"brown\") fox, Été!")
+
+    assert lemmas(tokens) == [
+        "the", " ", "quick", " ", "(", "\"", "brown", "\"", ")", " ", "fox", ", ", "ete", "!"
+    ]
+    assert tokens[0].kind == "word"
+    assert tokens[0].is_word()
+    assert tokens[1].is_separator()
+    assert tokens[1].separator_kind == "soft"
+    assert tokens[11].separator_kind == "hard"
+    assert tokens[0].script == "Latin"
+
+
+def test_offsets():
+    text = "Été à Paris"
+    tokens = charabia.Tokenizer().tokenize(text)
+
+    for token in tokens:
+        assert len(text[token.char_start:token.char_end]) == token.char_end - token.char_start
+        assert text.encode()[token.byte_start:token.byte_end].decode() == text[token.char_start:token.char_end]
+    assert tokens[0].byte_end == 5
+    assert tokens[0].char_end == 3
+
+
+def test_builder_options():
+    tokenizer = (
+        charabia.TokenizerBuilder()
+        .stop_words(["the"])
+        .separators([" "])
+        .words_dict(["New York"])
+        .lossy_normalization(False)
+        .build()
+    )
+    tokens = tokenizer.tokenize("the New York-Times")
+
+    assert lemmas(tokens) == ["the", " ", "New York", "-Times"]
+    assert tokens[0].is_stopword()
+
+
+def test_char_map():
+    tokenizer = charabia.TokenizerBuilder().create_char_map(True).build()
+    tokens = tokenizer.tokenize("Été")
+
+    assert tokens[0].lemma == "ete"
+    assert tokens[0].char_map == [(2, 1), (1, 1), (2, 1)]
+    assert charabia.Tokenizer().tokenize("Été")[0].char_map is None
+
+
+def test_builder_is_reusable():
+    builder = charabia.TokenizerBuilder().stop_words(["fox"])
+    first = builder.build()
+    second = builder.stop_words([]).build()
+
+    assert first.tokenize("fox")[0].is_stopword()
+    assert second.tokenize("fox")[0].is_word()
+
+
+def test_allow_list():
+    tokenizer = charabia.TokenizerBuilder().allow_list(["eng"]).build()
+    assert tokenizer.tokenize("hello")[0].language == "eng"
+
+    with pytest.raises(ValueError):
+        charabia.TokenizerBuilder().allow_list(["not a language"])
+
+
+def test_segment():
+    assert charabia.Tokenizer().segment("The quick fox") == ["The", " ", "quick", " ", "fox"]
+
+
+def test_detect():
+    assert charabia.detect_script("Привет мир") == "Cyrillic"
+    assert charabia.detect_script("1234") == "other"
+    assert charabia.detect_language("Привет, как у тебя дела?") == "rus"
+    assert charabia.detect_language("Bonjour tout le monde", allow_list=["fra", "eng"]) == "fra"
+    # the language of a Latin text is only detected with an allow list.
+    assert charabia.detect_language("Bonjour tout le monde") is None
+
+
+def test_token_equality():
+    tokenizer = charabia.Tokenizer()
+    assert tokenizer.tokenize("fox") == tokenizer.tokenize("fox")
+    assert tokenizer.tokenize("fox") != tokenizer.tokenize("dog")
+    assert repr(tokenizer.tokenize("fox")[0]).startswith('Token(lemma="fox", kind="word"')
+
+
+def test_shared_between_threads():
+    tokenizer = charabia.Tokenizer()
+    results = [None] * 8
+
+    def tokenize(index):
+        results[index] = lemmas(tokenizer.tokenize(f"document {index}"))
+
+    threads = [threading.Thread(target=tokenize, args=(i,)) for i in range(len(results))]
+    for thread in threads:
+        thread.start()
+    for thread in threads:
+        thread.join()
+
+    assert results == [["document", " ", str(i)] for i in range(len(results))]
@@ -0,0 +1,419 @@
+//! Command-line tool printing how a text is segmented, normalized and classified by charabia.
+//!
+//! ```sh
+//! echo "The quick (\"brown\") fox" | charabia --format tsv
+//! charabia --stop-words stop_words.txt --allow-list eng,fra document.txt
+//! ```
+
+use std::collections::BTreeSet;
+use std::fs;
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use charabia::{Language, Token, TokenizerBuilder};
+use clap::{Parser, ValueEnum};
+use fst::Set;
+
+/// Print the tokens of a text as segmented, normalized and classified by charabia.
+#[derive(Debug, Parser)]
+#[command(name = "charabia", version)]
+struct Opt {
+    /// Files to tokenize, the standard input is read if no file is given or if the file is `-`.
+    files: Vec<PathBuf>,
+
+    /// Output format.
+    #[arg(short, long, value_enum, default_value_t = Format::Table)]
+    format: Format,
+
+    /// Print the char_map of each token.
+    #[arg(long)]
+    char_map: bool,
+
+    /// File containing the stop words, one per line.
+    #[arg(long, value_name = "FILE")]
+    stop_words: Option<PathBuf>,
+
+    /// Separator replacing the default list of separators, can be repeated.
+    ///
+    /// The escape sequences `\n`, `\r`, `\t`, `\0` and `\\` are supported.
+    #[arg(long = "separator", value_name = "SEPARATOR")]
+    separators: Vec<String>,
+
+    /// File containing the words segmented before any other segmentation, one per line.
+    #[arg(long, value_name = "FILE")]
+    words_dict: Option<PathBuf>,
+
+    /// Comma-separated list of ISO 639-3 language codes allowed during the detection, like `eng,fra`.
+    #[arg(long, value_name = "LANGUAGES", value_delimiter = ',', value_parser = parse_language)]
+    allow_list: Vec<Language>,
+
+    /// Disable the lossy normalization, like the lowercasing or the removal of diacritics.
+    #[arg(long)]
+    no_lossy: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+    /// aligned columns readable by a human.
+    Table,
+    /// one JSON object per token.
+    Jsonl,
+    /// tab-separated values with a header.
+    Tsv,
+}
+
+fn main() -> ExitCode {
+    let opt = Opt::parse();
+    match run(&opt) {
+        Ok(()) => ExitCode::SUCCESS,
+        // the output has been closed, for instance by `head`.
+        Err(e) if e.kind() == io::ErrorKind::BrokenPipe => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("charabia: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(opt: &Opt) -> io::Result<()> {
+    let stop_words = opt.stop_words.as_deref().map(read_stop_words).transpose()?;
+    let separators: Vec<String> = opt.separators.iter().map(|s| unescape(s)).collect();
+    let separators: Vec<&str> = separators.iter().map(String::as_str).collect();
+    let words_dict = opt.words_dict.as_deref().map(read_lines).transpose()?;
+    let words_dict: Option<Vec<&str>> =
+        words_dict.as_ref().map(|words| words.iter().map(String::as_str).collect());
+
+    let mut builder = TokenizerBuilder::default();
+    if let Some(stop_words) = &stop_words {
+        builder.stop_words(stop_words);
+    }
+    if !separators.is_empty() {
+        builder.separators(&separators);
+    }
+    if let Some(words_dict) = &words_dict {
+        builder.words_dict(words_dict);
+    }
+    if !opt.allow_list.is_empty() {
+        builder.allow_list(&opt.allow_list);
+    }
+    builder.create_char_map(opt.char_map);
+    builder.lossy_normalization(!opt.no_lossy);
+    let tokenizer = builder.build();
+
+    let stdout = io::stdout();
+    let mut output = BufWriter::new(stdout.lock());
+    if opt.format == Format::Tsv {
+        write_tsv_header(&mut output, opt.char_map)?;
+    }
+
+    let stdin = [PathBuf::from("-")];
+    let files = if opt.files.is_empty() { &stdin[..] } else { &opt.files[..] };
+    for file in files {
+        let reader = open(file)?;
+        let tokens = tokenizer
+            .tokenize_reader(reader)
+            .reconstruct()
+            .map(|token| token.map_err(|e| with_path(e, file)));
+        match opt.format {
+            // the columns are aligned on the widest cell, so the whole file is kept in memory.
+            Format::Table => {
+                let tokens: Vec<_> = tokens.collect::<io::Result<_>>()?;
+                write_table(&mut output, &tokens, opt.char_map)?
+            }
+            Format::Jsonl => {
+                for token in tokens {
+                    let (original, token) = token?;
+                    write_jsonl(&mut output, &original, &token, opt.char_map)?;
+                }
+            }
+            Format::Tsv => {
+                for token in tokens {
+                    let (original, token) = token?;
+                    write_tsv(&mut output, &original, &token, opt.char_map)?;
+                }
+            }
+        }
+    }
+
+    output.flush()
+}
+
+fn parse_language(code: &str) -> Result<Language, String> {
+    Language::from_code(code).ok_or_else(|| format!("unknown language code `{code}`"))
+}
+
+fn open(path: &Path) -> io::Result<Box<dyn BufRead>> {
+    if path.as_os_str() == "-" {
+        Ok(Box::new(io::stdin().lock()))
+    } else {
+        let file = fs::File::open(path).map_err(|e| with_path(e, path))?;
+        Ok(Box::new(BufReader::new(file)))
+    }
+}
+
+/// Reads the non-empty lines of a file.
+fn read_lines(path: &Path) -> io::Result<Vec<String>> {
+    let content = fs::read_to_string(path).map_err(|e| with_path(e, path))?;
+    Ok(content.lines().filter(|line| !line.is_empty()).map(str::to_string).collect())
+}
+
+fn read_stop_words(path: &Path) -> io::Result<Set<Vec<u8>>> {
+    // an fst Set must be built from sorted and deduplicated words.
+    let words: BTreeSet<_> = read_lines(path)?.into_iter().collect();
+    Set::from_iter(words).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+fn with_path(error: io::Error, path: &Path) -> io::Error {
+    io::Error::new(error.kind(), format!("{}: {error}", path.display()))
+}
+
+/// Replaces the escape sequences of a separator given on the command line.
+fn unescape(s: &str) -> String {
+    let mut output = String::with_capacity(s.len());
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            output.push(c);
+            continue;
+        }
+
+        match chars.next() {
+            Some('n') => output.push('\n'),
+            Some('r') => output.push('\r'),
+            Some('t') => output.push('\t'),
+            Some('0') => output.push('\0'),
+            Some('\\') => output.push('\\'),
+            Some(other) => {
+                output.push('\\');
+                output.push(other);
+            }
+            None => output.push('\\'),
+        }
+    }
+
+    output
+}
+
+/// Escapes the characters that would break a TSV line.
+fn escape_tsv(s: &str) -> String {
+    let mut output = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '\t' => output.push_str("\\t"),
+            '\n' => output.push_str("\\n"),
+            '\r' => output.push_str("\\r"),
+            '\\' => output.push_str("\\\\"),
+            c => output.push(c),
+        }
+    }
+
+    output
+}
+
+fn language_code(token: &Token) -> &'static str {
+    token.language.map_or("-", |language| language.code())
+}
+
+fn format_char_map(token: &Token) -> String {
+    match &token.char_map {
+        Some(char_map) => {
+            let pairs: Vec<_> = char_map.iter().map(|(o, n)| format!("{o}:{n}")).collect();
+            pairs.join(",")
+        }
+        None => "-".to_string(),
+    }
+}
+
+fn write_table(
+    output: &mut impl Write,
+    tokens: &[(String, Token)],
+    char_map: bool,
+) -> io::Result<()> {
+    let rows: Vec<Vec<String>> = tokens
+        .iter()
+        .map(|(original, token)| {
+            let mut row = vec![
+                format!("{original:?}"),
+                format!("{:?}", token.lemma()),
+                format!("{:?}", token.kind),
+                token.script.name().to_string(),
+                language_code(token).to_string(),
+                format!("{}..{}", token.byte_start, token.byte_end),
+                format!("{}..{}", token.char_start, token.char_end),
+            ];
+            if char_map {
+                row.push(format_char_map(token));
+            }
+            row
+        })
+        .collect();
+
+    let mut header = vec!["original", "lemma", "kind", "script", "language", "bytes", "chars"];
+    if char_map {
+        header.push("char_map");
+    }
+
+    let mut widths: Vec<usize> = header.iter().map(|h| h.chars().count()).collect();
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let header: Vec<String> = header.iter().map(|h| h.to_string()).collect();
+    for row in std::iter::once(&header).chain(&rows) {
+        let cells: Vec<_> =
+            row.iter().zip(&widths).map(|(cell, width)| pad(cell, *width)).collect();
+        writeln!(output, "{}", cells.join("  ").trim_end())?;
+    }
+
+    Ok(())
+}
+
+/// Pads a cell with spaces, `format!("{:<width$}")` counts the bytes and not the characters.
+fn pad(cell: &str, width: usize) -> String {
+    let padding = width.saturating_sub(cell.chars().count());
+    format!("{cell}{}", " ".repeat(padding))
+}
+
+fn write_jsonl(
+    output: &mut impl Write,
+    original: &str,
+    token: &Token,
+    char_map: bool,
+) -> io::Result<()> {
+    // same representation of the kind, the script and the language as the other formats.
+    let mut object = serde_json::json!({
+        "original": original,
+        "lemma": token.lemma(),
+        "kind": format!("{:?}", token.kind),
+        "script": token.script.name(),
+        "language": token.language.map(|language| language.code()),
+        "byte_start": token.byte_start,
+        "byte_end": token.byte_end,
+        "char_start": token.char_start,
+        "char_end": token.char_end,
+    });
+    if char_map {
+        object["char_map"] = serde_json::json!(token.char_map);
+    }
+    serde_json::to_writer(&mut *output, &object)?;
+    writeln!(output)
+}
+
+fn write_tsv_header(output: &mut impl Write, char_map: bool) -> io::Result<()> {
+    write!(
+        output,
+        "original\tlemma\tkind\tscript\tlanguage\tbyte_start\tbyte_end\tchar_start\tchar_end"
+    )?;
+    if char_map {
+        write!(output, "\tchar_map")?;
+    }
+    writeln!(output)
+}
+
+fn write_tsv(
+    output: &mut impl Write,
+    original: &str,
+    token: &Token,
+    char_map: bool,
+) -> io::Result<()> {
+    write!(
+        output,
+        "{}\t{}\t{:?}\t{}\t{}\t{}\t{}\t{}\t{}",
+        escape_tsv(original),
+        escape_tsv(token.lemma()),
+        token.kind,
+        token.script.name(),
+        language_code(token),
+        token.byte_start,
+        token.byte_end,
+        token.char_start,
+        token.char_end,
+    )?;
+    if char_map {
+        write!(output, "\t{}", format_char_map(token))?;
+    }
+    writeln!(output)
+}
+
+#[cfg(test)]
+mod test {
+    use charabia::Tokenize;
+
+    use super::*;
+
+    fn reconstruct(text: &str) -> Vec<(String, Token<'static>)> {
+        text.reconstruct()
+            .map(|(original, token)| (original.to_string(), token.into_owned()))
+            .collect()
+    }
+
+    #[test]
+    fn unescape_separators() {
+        assert_eq!(unescape(r"\n"), "\n");
+        assert_eq!(unescape(r"a\tb\\"), "a\tb\\");
+        assert_eq!(unescape(r"\x"), r"\x");
+        assert_eq!(unescape("\\"), "\\");
+    }
+
+    #[test]
+    fn tsv_output() {
+        let mut output = Vec::new();
+        for (original, token) in reconstruct("Hello\tworld") {
+            write_tsv(&mut output, &original, &token, false).unwrap();
+        }
+
+        let output = String::from_utf8(output).unwrap();
+        let lines: Vec<_> = output.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[0], "Hello\thello\tWord\tLatin\t-\t0\t5\t0\t5");
+        assert!(lines[1].starts_with("\\t\t\\t\tSeparator(Soft)"));
+    }
+
+    #[test]
+    fn jsonl_output() {
+        let mut output = Vec::new();
+        for (original, token) in reconstruct("Hello") {
+            write_jsonl(&mut output, &original, &token, true).unwrap();
+        }
+
+        let value: serde_json::Value = serde_json::from_slice(&output).unwrap();
+        assert_eq!(value["original"], "Hello");
+        assert_eq!(value["lemma"], "hello");
+        assert_eq!(value["kind"], "Word");
+        assert_eq!(value["script"], "Latin");
+        assert_eq!(value["char_map"], serde_json::Value::Null);
+    }
+
+    #[test]
+    fn jsonl_language_like_tsv() {
+        let (original, token) = reconstruct("السلام").remove(0);
+        let mut jsonl = Vec::new();
+        write_jsonl(&mut jsonl, &original, &token, false).unwrap();
+        let mut tsv = Vec::new();
+        write_tsv(&mut tsv, &original, &token, false).unwrap();
+
+        let value: serde_json::Value = serde_json::from_slice(&jsonl).unwrap();
+        let tsv = String::from_utf8(tsv).unwrap();
+        assert_eq!(value["language"], "ara");
+        assert_eq!(tsv.split('\t').nth(4), Some("ara"));
+    }
+
+    #[test]
+    fn table_aligns_columns() {
+        let mut output = Vec::new();
+        write_table(&mut output, &reconstruct("été fox"), false).unwrap();
+
+        let output = String::from_utf8(output).unwrap();
+        let mut lines = output.lines();
+        let header = lines.next().unwrap();
+        let lemma_column = header[..header.find("lemma").unwrap()].chars().count();
+        for line in lines {
+            // the lemma is the second quoted cell of the line.
+            let (index, _) = line.match_indices('"').nth(2).unwrap();
+            assert_eq!(line[..index].chars().count(), lemma_column, "{line}");
+        }
+    }
+}
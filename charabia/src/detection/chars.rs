@@ -180,6 +180,11 @@ pub(crate) fn is_khmer(ch: char) -> bool {
     matches!(ch, '\u{1780}'..='\u{17FF}' | '\u{19E0}'..='\u{19FF}')
 }
 
+// Based on: https://en.wikipedia.org/wiki/Lao_(Unicode_block)
+pub(crate) fn is_lao(ch: char) -> bool {
+    matches!(ch, '\u{0E80}'..='\u{0EFF}')
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,83 @@
+use std::collections::HashMap;
+use std::sync::LazyLock;
+
+use irg_kvariants::{KVariantClass, KVARIANTS};
+use serde::{Deserialize, Serialize};
+
+/// Variant of the Chinese script, see [`Token::chinese_variant`](crate::Token::chinese_variant).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ChineseVariant {
+    /// Traditional Chinese, `Hant` in ISO 15924.
+    Traditional,
+    /// Simplified Chinese, `Hans` in ISO 15924.
+    Simplified,
+}
+
+/// Simplified form of each Traditional ideograph having one in the kVariants.
+///
+/// When several Simplified ideographs are variants of the same Traditional ideograph,
+/// the one with the lowest code point is kept to stay deterministic.
+pub(crate) static SIMPLIFIED_FORMS: LazyLock<HashMap<char, char>> = LazyLock::new(|| {
+    let mut map = HashMap::new();
+    for kvariant in KVARIANTS.values() {
+        if kvariant.classification == KVariantClass::Simplified {
+            map.entry(kvariant.destination_ideograph)
+                .and_modify(|simplified: &mut char| {
+                    *simplified = kvariant.source_ideograph.min(*simplified)
+                })
+                .or_insert(kvariant.source_ideograph);
+        }
+    }
+
+    map
+});
+
+impl ChineseVariant {
+    /// Detects the variant of a Chinese text by counting its Traditional and Simplified specific ideographs.
+    ///
+    /// Returns `None` if the text contains as many ideographs specific to both variants,
+    /// like a text only containing ideographs shared by both variants.
+    pub fn detect(text: &str) -> Option<ChineseVariant> {
+        let (traditional, simplified) =
+            text.chars().fold((0, 0), |(traditional, simplified), c| {
+                if SIMPLIFIED_FORMS.contains_key(&c) {
+                    (traditional + 1, simplified)
+                } else if KVARIANTS
+                    .get(&c)
+                    .is_some_and(|k| k.classification == KVariantClass::Simplified)
+                {
+                    (traditional, simplified + 1)
+                } else {
+                    (traditional, simplified)
+                }
+            });
+
+        match traditional.cmp(&simplified) {
+            std::cmp::Ordering::Greater => Some(ChineseVariant::Traditional),
+            std::cmp::Ordering::Less => Some(ChineseVariant::Simplified),
+            std::cmp::Ordering::Equal => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn detect() {
+        assert_eq!(ChineseVariant::detect("東亞們"), Some(ChineseVariant::Traditional));
+        assert_eq!(ChineseVariant::detect("东亚们"), Some(ChineseVariant::Simplified));
+        assert_eq!(ChineseVariant::detect("東亚们"), Some(ChineseVariant::Simplified));
+        // ideographs shared by both variants.
+        assert_eq!(ChineseVariant::detect("生而自由"), None);
+        assert_eq!(ChineseVariant::detect(""), None);
+    }
+
+    #[test]
+    fn simplified_forms() {
+        assert_eq!(SIMPLIFIED_FORMS.get(&'亞'), Some(&'亚'));
+        assert_eq!(SIMPLIFIED_FORMS.get(&'東'), Some(&'东'));
+        assert_eq!(SIMPLIFIED_FORMS.get(&'亚'), None);
+    }
+}
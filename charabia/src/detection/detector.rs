@@ -0,0 +1,97 @@
+use std::fmt;
+
+use whatlang::Detector;
+
+use super::Language;
+
+/// Confidence above which a detected language is considered as reliable.
+pub const RELIABLE_CONFIDENCE: f64 = 0.9;
+
+/// Trait defining a language detector used by [`StrDetection`].
+///
+/// The confidence returned with each detected [`Language`] is a value between `0.0` and `1.0`,
+/// a language detected with a confidence greater than [`RELIABLE_CONFIDENCE`] is considered as reliable.
+///
+/// [`StrDetection`]: crate::StrDetection
+pub trait LanguageDetector: Sync + Send {
+    /// Detects the language of the text among the languages of the `allow_list`,
+    /// or among all the supported languages if there is no `allow_list`.
+    fn detect(&self, text: &str, allow_list: Option<&[Language]>) -> Option<(Language, f64)>;
+
+    /// Returns at most `n` candidate languages of the text with their confidence,
+    /// ranked from the most to the least likely.
+    ///
+    /// By default, only the detected language is returned.
+    fn candidates(
+        &self,
+        text: &str,
+        allow_list: Option<&[Language]>,
+        n: usize,
+    ) -> Vec<(Language, f64)> {
+        self.detect(text, allow_list).into_iter().take(n).collect()
+    }
+}
+
+impl fmt::Debug for dyn LanguageDetector + '_ {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("LanguageDetector")
+    }
+}
+
+/// [`LanguageDetector`] based on whatlang, used by default.
+///
+/// The languages not supported by whatlang, like [`Language::Isl`], are never detected.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct WhatlangDetector;
+
+impl WhatlangDetector {
+    /// Returns the languages of the allow list supported by whatlang.
+    fn whatlang_languages(allow_list: &[Language]) -> Vec<whatlang::Lang> {
+        allow_list.iter().filter_map(Language::to_whatlang).collect()
+    }
+
+    fn detector(allow_list: Option<&[Language]>) -> Detector {
+        allow_list.map(Self::whatlang_languages).map(Detector::with_allowlist).unwrap_or_default()
+    }
+}
+
+impl LanguageDetector for WhatlangDetector {
+    fn detect(&self, text: &str, allow_list: Option<&[Language]>) -> Option<(Language, f64)> {
+        Self::detector(allow_list)
+            .detect(text)
+            .map(|info| (Language::from(info.lang()), info.confidence()))
+    }
+
+    /// whatlang only returns the most likely language, so each candidate is detected in a separate run
+    /// among the allowed languages that are not already detected.
+    /// The confidences come from these separate runs and the candidates are sorted by decreasing confidence,
+    /// the candidates having the same confidence stay in their detection order.
+    fn candidates(
+        &self,
+        text: &str,
+        allow_list: Option<&[Language]>,
+        n: usize,
+    ) -> Vec<(Language, f64)> {
+        let mut remaining: Vec<whatlang::Lang> = match allow_list {
+            Some(allow_list) => Self::whatlang_languages(allow_list),
+            None => whatlang::Lang::all().to_vec(),
+        };
+
+        let mut candidates = Vec::new();
+        while candidates.len() < n && !remaining.is_empty() {
+            let detector = Detector::with_allowlist(remaining.clone());
+            let Some(info) = detector.detect(text) else { break };
+            // whatlang can return a language outside of the allow list for some scripts,
+            // like Hebrew or Japanese, there is no other candidate in this case.
+            let Some(position) = remaining.iter().position(|lang| *lang == info.lang()) else {
+                break;
+            };
+            remaining.swap_remove(position);
+            candidates.push((Language::from(info.lang()), info.confidence()));
+        }
+
+        // the sort is stable, so the candidates having the same confidence keep their detection order.
+        candidates.sort_by(|(_, a), (_, b)| b.total_cmp(a));
+        candidates
+    }
+}
@@ -1,9 +1,14 @@
+pub use chinese::ChineseVariant;
+pub use detector::{LanguageDetector, WhatlangDetector, RELIABLE_CONFIDENCE};
+pub use ngram::NgramDetector;
 pub use script_language::{Language, Script};
-use whatlang::Detector;
 
 // file copy pasted from whatlang.
 #[allow(dead_code)]
 mod chars;
+pub(crate) mod chinese;
+mod detector;
+mod ngram;
 mod script_language;
 
 pub struct StrDetection<'o, 'al> {
@@ -11,11 +16,37 @@ pub struct StrDetection<'o, 'al> {
     pub script: Option<Script>,
     pub language: Option<Language>,
     allow_list: Option<&'al [Language]>,
+    detector: &'al dyn LanguageDetector,
+    min_confidence: f64,
+    document_language: Option<(Language, usize)>,
 }
 
 impl<'o, 'al> StrDetection<'o, 'al> {
     pub fn new(inner: &'o str, allow_list: Option<&'al [Language]>) -> Self {
-        Self { inner, script: None, language: None, allow_list }
+        Self {
+            inner,
+            script: None,
+            language: None,
+            allow_list,
+            detector: &WhatlangDetector,
+            min_confidence: 0.0,
+            document_language: None,
+        }
+    }
+
+    /// Detect the language with the given [`LanguageDetector`] instead of the default [`WhatlangDetector`].
+    pub fn with_detector(mut self, detector: &'al dyn LanguageDetector) -> Self {
+        self.detector = detector;
+        self
+    }
+
+    /// Ignore the detected languages having a confidence lower than `min_confidence`,
+    /// [`StrDetection::language`] returns `None` instead of guessing.
+    ///
+    /// The confidence is a value between `0.0` and `1.0`, by default no language is ignored.
+    pub fn with_min_confidence(mut self, min_confidence: f64) -> Self {
+        self.min_confidence = min_confidence;
+        self
     }
 
     pub fn script(&mut self) -> Script {
@@ -30,28 +61,80 @@ impl<'o, 'al> StrDetection<'o, 'al> {
             None => match self.allow_list {
                 Some([unique_language]) => Some(*unique_language),
                 None if Self::detect_script(inner) == Script::Latin => None,
-                _otherwise => Self::detect_lang(inner, self.allow_list),
+                _otherwise => match self.document_language {
+                    Some((language, min_run_len)) => {
+                        self.detect_run_lang(min_run_len).or(Some(language))
+                    }
+                    None => self.detect_lang(),
+                },
             },
         };
 
         self.language
     }
 
+    /// Use the `language` detected on the whole document instead of detecting the language of the text,
+    /// unless the text contains at least `min_run_len` characters of its script and its language is reliably detected.
+    ///
+    /// This prevents short texts, like a single Kanji word in a Japanese document,
+    /// to be detected as another language than the rest of the document.
+    pub fn with_document_language(mut self, language: Language, min_run_len: usize) -> Self {
+        self.document_language = Some((language, min_run_len));
+        self
+    }
+
+    /// Returns at most `n` candidate languages of the text with their confidence,
+    /// ranked from the most to the least likely.
+    ///
+    /// The confidence is a value between `0.0` and `1.0` given by the [`LanguageDetector`],
+    /// see [`LanguageDetector::candidates`] for the ranking of the used detector.
+    /// The candidates having a confidence lower than the minimum confidence are skipped.
+    pub fn language_candidates(&self, n: usize) -> Vec<(Language, f64)> {
+        if let Some([unique_language]) = self.allow_list {
+            return if n > 0 { vec![(*unique_language, 1.0)] } else { Vec::new() };
+        }
+
+        let mut candidates = self.detector.candidates(self.inner, self.allow_list, n);
+        candidates.retain(|(_, confidence)| *confidence >= self.min_confidence);
+        candidates
+    }
+
     /// detect script with whatlang,
-    /// if no script is detected, return Script::Other
+    /// if whatlang doesn't detect any script, return Script::Lao for a Lao text which is not supported by whatlang,
+    /// otherwise return Script::Other
     fn detect_script(text: &str) -> Script {
-        whatlang::detect_script(text).map(Script::from).unwrap_or_default()
+        whatlang::detect_script(text).map(Script::from).unwrap_or_else(|| {
+            if text.chars().any(chars::is_lao) {
+                Script::Lao
+            } else {
+                Script::Other
+            }
+        })
+    }
+
+    /// detect lang with the language detector
+    /// if no language is detected with at least `min_confidence`, return None
+    fn detect_lang(&self) -> Option<Language> {
+        self.detector
+            .detect(self.inner, self.allow_list)
+            .filter(|(_, confidence)| *confidence >= self.min_confidence)
+            .map(|(language, _)| language)
     }
 
-    /// detect lang with whatlang
-    /// if no language is detected, return Language::Other
-    fn detect_lang(text: &str, allow_list: Option<&[Language]>) -> Option<Language> {
-        let detector = allow_list
-            .map(|allow_list| allow_list.iter().map(|lang| (*lang).into()).collect())
-            .map(Detector::with_allowlist)
-            .unwrap_or_default();
+    /// detect lang of a run of a document with the language detector
+    /// if the run is shorter than `min_run_len` characters or if its language is not reliably detected, return None
+    fn detect_run_lang(&self, min_run_len: usize) -> Option<Language> {
+        let run_len = self.inner.chars().filter(|c| Script::from(*c) != Script::Other).count();
+        if run_len < min_run_len {
+            return None;
+        }
 
-        detector.detect_lang(text).map(Language::from)
+        self.detector
+            .detect(self.inner, self.allow_list)
+            .filter(|(_, confidence)| {
+                *confidence > RELIABLE_CONFIDENCE && *confidence >= self.min_confidence
+            })
+            .map(|(language, _)| language)
     }
 }
 
@@ -64,3 +147,49 @@ impl<'o, 'al> Detect<'o, 'al> for &str {
         StrDetection::new(self, allow_list)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn language_candidates() {
+        let text = "Ceci est un texte écrit en français pour tester la détection de la langue.";
+        let detection = StrDetection::new(text, None);
+        let candidates = detection.language_candidates(3);
+        assert_eq!(candidates.len(), 3);
+        assert_eq!(candidates[0].0, Language::Fra);
+        assert!(candidates.iter().all(|(_, confidence)| (0.0..=1.0).contains(confidence)));
+
+        let allow_list = [Language::Eng, Language::Fra];
+        let detection = StrDetection::new(text, Some(&allow_list));
+        let languages: Vec<_> =
+            detection.language_candidates(5).into_iter().map(|(l, _)| l).collect();
+        assert_eq!(languages, [Language::Fra, Language::Eng]);
+
+        let detection = StrDetection::new(text, Some(&allow_list[..1]));
+        assert_eq!(detection.language_candidates(5), [(Language::Eng, 1.0)]);
+        assert!(detection.language_candidates(0).is_empty());
+    }
+
+    #[test]
+    fn language_candidates_decreasing_confidence() {
+        let detection = StrDetection::new("the shells of the sea", None);
+        let candidates = detection.language_candidates(5);
+        assert_eq!(candidates.len(), 5);
+        assert_eq!(candidates[0].0, Language::Eng);
+        assert!(candidates.windows(2).all(|pair| pair[0].1 >= pair[1].1));
+    }
+
+    #[test]
+    fn min_confidence() {
+        let text = "мир";
+        let mut detection = StrDetection::new(text, None);
+        assert!(detection.language().is_some());
+
+        let mut detection = StrDetection::new(text, None).with_min_confidence(0.9);
+        assert_eq!(detection.language(), None);
+        let candidates = detection.language_candidates(3);
+        assert!(candidates.iter().all(|(_, confidence)| *confidence >= 0.9));
+    }
+}
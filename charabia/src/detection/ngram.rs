@@ -0,0 +1,250 @@
+use std::collections::HashMap;
+use std::io::{self, BufRead};
+
+use super::{Language, LanguageDetector};
+
+/// Default number of n-grams kept in the profile of each language.
+pub const DEFAULT_PROFILE_LEN: usize = 300;
+
+/// Length of the longest n-grams of a profile.
+const MAX_NGRAM_LEN: usize = 3;
+
+/// [`LanguageDetector`] comparing the character n-grams of a text to language profiles trained on local corpora.
+///
+/// The profile of a language is the ranked list of its most frequent n-grams,
+/// the detected language is the one whose profile is the closest to the profile of the text
+/// using the "out-of-place" distance of Cavnar and Trenkle.
+/// The confidence of a language is the similarity between both profiles, from `0.0` to `1.0`.
+///
+/// # Example
+///
+/// ```
+/// use charabia::{Language, LanguageDetector, NgramDetector};
+///
+/// let mut detector = NgramDetector::new();
+/// detector
+///     .train(Language::Eng, "the cat is sleeping on the sofa while the dog is playing in the garden")
+///     .train(Language::Fra, "le chat dort sur le canapé pendant que le chien joue dans le jardin");
+///
+/// let (language, _confidence) = detector.detect("the dog is sleeping", None).unwrap();
+/// assert_eq!(language, Language::Eng);
+/// ```
+#[derive(Debug, Clone)]
+pub struct NgramDetector {
+    profile_len: usize,
+    profiles: Vec<LanguageProfile>,
+}
+
+#[derive(Debug, Clone)]
+struct LanguageProfile {
+    language: Language,
+    counts: HashMap<String, usize>,
+    ranks: HashMap<String, usize>,
+}
+
+impl NgramDetector {
+    /// Creates a detector without any trained language.
+    pub fn new() -> Self {
+        Self { profile_len: DEFAULT_PROFILE_LEN, profiles: Vec::new() }
+    }
+
+    /// Sets the number of n-grams kept in the profile of each language, [`DEFAULT_PROFILE_LEN`] by default.
+    pub fn with_profile_len(mut self, profile_len: usize) -> Self {
+        self.profile_len = profile_len.max(1);
+        for profile in &mut self.profiles {
+            profile.ranks = ranks(&profile.counts, self.profile_len);
+        }
+        self
+    }
+
+    /// Trains the profile of a language with a corpus,
+    /// training the same language several times adds the corpora together.
+    pub fn train(&mut self, language: Language, corpus: &str) -> &mut Self {
+        let position = match self.profiles.iter().position(|p| p.language == language) {
+            Some(position) => position,
+            None => {
+                let counts = HashMap::new();
+                let ranks = HashMap::new();
+                self.profiles.push(LanguageProfile { language, counts, ranks });
+                self.profiles.len() - 1
+            }
+        };
+
+        let profile = &mut self.profiles[position];
+        for ngram in ngrams(corpus) {
+            *profile.counts.entry(ngram).or_default() += 1;
+        }
+        profile.ranks = ranks(&profile.counts, self.profile_len);
+
+        self
+    }
+
+    /// Trains the profile of a language with a corpus read line by line, like a local file.
+    pub fn train_reader<R: BufRead>(
+        &mut self,
+        language: Language,
+        reader: R,
+    ) -> io::Result<&mut Self> {
+        for line in reader.lines() {
+            self.train(language, &line?);
+        }
+
+        Ok(self)
+    }
+
+    /// Returns the trained languages.
+    pub fn languages(&self) -> impl Iterator<Item = Language> + '_ {
+        self.profiles.iter().map(|profile| profile.language)
+    }
+}
+
+impl Default for NgramDetector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LanguageDetector for NgramDetector {
+    fn detect(&self, text: &str, allow_list: Option<&[Language]>) -> Option<(Language, f64)> {
+        self.candidates(text, allow_list, 1).into_iter().next()
+    }
+
+    /// The candidates are ranked by decreasing similarity with the text.
+    fn candidates(
+        &self,
+        text: &str,
+        allow_list: Option<&[Language]>,
+        n: usize,
+    ) -> Vec<(Language, f64)> {
+        let mut counts = HashMap::new();
+        for ngram in ngrams(text) {
+            *counts.entry(ngram).or_insert(0) += 1;
+        }
+        let text_profile = ranked(&counts, self.profile_len);
+        if text_profile.is_empty() {
+            return Vec::new();
+        }
+
+        let max_distance = (text_profile.len() * self.profile_len) as f64;
+        let mut candidates: Vec<_> = self
+            .profiles
+            .iter()
+            .filter(|profile| !profile.ranks.is_empty())
+            .filter(|profile| allow_list.is_none_or(|list| list.contains(&profile.language)))
+            .map(|profile| {
+                let distance: usize = text_profile
+                    .iter()
+                    .enumerate()
+                    .map(|(rank, ngram)| match profile.ranks.get(*ngram) {
+                        Some(language_rank) => rank.abs_diff(*language_rank),
+                        None => self.profile_len,
+                    })
+                    .sum();
+                (profile.language, 1.0 - distance as f64 / max_distance)
+            })
+            .collect();
+
+        candidates.sort_by(|(_, a), (_, b)| b.total_cmp(a));
+        candidates.truncate(n);
+        candidates
+    }
+}
+
+/// Returns the n-grams of each word of the text, the words are lowercased and surrounded by `_`,
+/// the digits and the punctuation are ignored.
+fn ngrams(text: &str) -> impl Iterator<Item = String> + '_ {
+    text.split(|c: char| !c.is_alphabetic()).filter(|word| !word.is_empty()).flat_map(|word| {
+        let chars: Vec<char> = std::iter::once('_')
+            .chain(word.chars().flat_map(char::to_lowercase))
+            .chain(['_'])
+            .collect();
+        (1..=MAX_NGRAM_LEN).flat_map(move |len| {
+            chars
+                .windows(len)
+                .filter(|ngram| ngram != &['_'])
+                .map(|ngram| ngram.iter().collect())
+                .collect::<Vec<String>>()
+        })
+    })
+}
+
+/// Returns the `profile_len` most frequent n-grams, the ties are ordered alphabetically.
+fn ranked(counts: &HashMap<String, usize>, profile_len: usize) -> Vec<&String> {
+    let mut ngrams: Vec<_> = counts.iter().collect();
+    ngrams.sort_by(|(a, a_count), (b, b_count)| b_count.cmp(a_count).then_with(|| a.cmp(b)));
+    ngrams.into_iter().take(profile_len).map(|(ngram, _)| ngram).collect()
+}
+
+fn ranks(counts: &HashMap<String, usize>, profile_len: usize) -> HashMap<String, usize> {
+    ranked(counts, profile_len)
+        .into_iter()
+        .enumerate()
+        .map(|(rank, ngram)| (ngram.clone(), rank))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::Cursor;
+
+    use super::*;
+
+    const ENGLISH: &str = "The quick brown fox jumps over the lazy dog. \
+        She sells sea shells by the sea shore, and the shells she sells are surely sea shells. \
+        How much wood would a woodchuck chuck if a woodchuck could chuck wood?";
+    const FRENCH: &str = "Portez ce vieux whisky au juge blond qui fume. \
+        Les chaussettes de l'archiduchesse sont-elles sèches ou archi-sèches ? \
+        Un chasseur sachant chasser doit savoir chasser sans son chien.";
+    const GERMAN: &str = "Zwölf Boxkämpfer jagen Viktor quer über den großen Sylter Deich. \
+        Fischers Fritz fischt frische Fische, frische Fische fischt Fischers Fritz. \
+        Blaukraut bleibt Blaukraut und Brautkleid bleibt Brautkleid.";
+
+    fn detector() -> NgramDetector {
+        let mut detector = NgramDetector::new();
+        detector
+            .train(Language::Eng, ENGLISH)
+            .train(Language::Fra, FRENCH)
+            .train(Language::Deu, GERMAN);
+        detector
+    }
+
+    #[test]
+    fn detect() {
+        let detector = detector();
+        assert_eq!(detector.detect("the shells of the sea", None).unwrap().0, Language::Eng);
+        assert_eq!(detector.detect("le chien du chasseur", None).unwrap().0, Language::Fra);
+        assert_eq!(detector.detect("die frische Fische", None).unwrap().0, Language::Deu);
+        assert_eq!(detector.detect("", None), None);
+        assert_eq!(detector.detect("42 !", None), None);
+        assert_eq!(NgramDetector::new().detect("the shells of the sea", None), None);
+    }
+
+    #[test]
+    fn allow_list() {
+        let detector = detector();
+        let allow_list = [Language::Fra, Language::Deu];
+        let (language, _) = detector.detect("the shells of the sea", Some(&allow_list)).unwrap();
+        assert_ne!(language, Language::Eng);
+        assert_eq!(detector.detect("the shells of the sea", Some(&[Language::Jpn])), None);
+    }
+
+    #[test]
+    fn candidates() {
+        let detector = detector();
+        let candidates = detector.candidates("the shells of the sea", None, 5);
+        assert_eq!(candidates.len(), 3);
+        assert_eq!(candidates[0].0, Language::Eng);
+        assert!(candidates.windows(2).all(|w| w[0].1 >= w[1].1));
+        assert!(candidates.iter().all(|(_, confidence)| (0.0..=1.0).contains(confidence)));
+        assert_eq!(detector.candidates("the shells of the sea", None, 1).len(), 1);
+    }
+
+    #[test]
+    fn train_reader() {
+        let mut detector = NgramDetector::new().with_profile_len(100);
+        detector.train_reader(Language::Eng, Cursor::new(ENGLISH.replace(". ", ".\n"))).unwrap();
+        detector.train(Language::Fra, FRENCH);
+        assert_eq!(detector.languages().collect::<Vec<_>>(), [Language::Eng, Language::Fra]);
+        assert_eq!(detector.detect("the shells of the sea", None).unwrap().0, Language::Eng);
+    }
+}
@@ -7,48 +7,141 @@ use serde::{Deserialize, Serialize};
 use super::chars;
 
 macro_rules! make_language {
-    ($($language:tt), +) => {
+    ($($language:ident => $code:literal), +) => {
+        /// Language of a text, identified by its ISO 639-3 code.
+        ///
+        /// Only a part of the languages can be detected by whatlang, see [`Language::to_whatlang`].
         #[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Serialize, Deserialize, PartialOrd, Ord)]
         pub enum Language {
-            Zho,
             $($language),+,
         }
-        impl From<whatlang::Lang> for Language {
-            fn from(other: whatlang::Lang) -> Language {
-                match other {
-                    $(whatlang::Lang::$language => Language::$language), +
+
+        impl Language {
+            /// Returns the ISO 639-3 code of the language.
+            pub fn code(&self) -> &'static str {
+                match self {
+                    $(Language::$language => $code), +,
+                }
+            }
+
+            /// Returns the language corresponding to an ISO 639-3 code.
+            pub fn from_code<S: AsRef<str>>(code: S) -> Option<Language> {
+                match code.as_ref() {
+                    $($code => Some(Language::$language)), +,
+                    _ => None,
                 }
             }
         }
+    };
+}
 
-        impl From<Language> for whatlang::Lang {
-            fn from(other: Language) -> whatlang::Lang {
+make_language! {
+    Zho => "zho",
+    Epo => "epo",
+    Eng => "eng",
+    Rus => "rus",
+    Cmn => "cmn",
+    Spa => "spa",
+    Por => "por",
+    Ita => "ita",
+    Ben => "ben",
+    Fra => "fra",
+    Deu => "deu",
+    Ukr => "ukr",
+    Kat => "kat",
+    Ara => "ara",
+    Hin => "hin",
+    Jpn => "jpn",
+    Heb => "heb",
+    Yid => "yid",
+    Pol => "pol",
+    Amh => "amh",
+    Jav => "jav",
+    Kor => "kor",
+    Nob => "nob",
+    Dan => "dan",
+    Swe => "swe",
+    Fin => "fin",
+    Tur => "tur",
+    Nld => "nld",
+    Hun => "hun",
+    Ces => "ces",
+    Ell => "ell",
+    Bul => "bul",
+    Bel => "bel",
+    Mar => "mar",
+    Kan => "kan",
+    Ron => "ron",
+    Slv => "slv",
+    Hrv => "hrv",
+    Srp => "srp",
+    Mkd => "mkd",
+    Lit => "lit",
+    Lav => "lav",
+    Est => "est",
+    Tam => "tam",
+    Vie => "vie",
+    Urd => "urd",
+    Tha => "tha",
+    Guj => "guj",
+    Uzb => "uzb",
+    Pan => "pan",
+    Aze => "aze",
+    Ind => "ind",
+    Tel => "tel",
+    Pes => "pes",
+    Mal => "mal",
+    Ori => "ori",
+    Mya => "mya",
+    Nep => "nep",
+    Sin => "sin",
+    Khm => "khm",
+    Tuk => "tuk",
+    Aka => "aka",
+    Zul => "zul",
+    Sna => "sna",
+    Afr => "afr",
+    Lat => "lat",
+    Slk => "slk",
+    Cat => "cat",
+    Tgl => "tgl",
+    Hye => "hye",
+    Isl => "isl",
+    Eus => "eus",
+    Glg => "glg",
+    Cym => "cym",
+    Gle => "gle",
+    Msa => "msa",
+    Swa => "swa",
+    Mon => "mon"
+}
+
+macro_rules! make_whatlang_conversions {
+    ($($language:ident), +) => {
+        impl From<whatlang::Lang> for Language {
+            fn from(other: whatlang::Lang) -> Language {
                 match other {
-                    Language::Zho => whatlang::Lang::Cmn,
-                    $(Language::$language => whatlang::Lang::$language), +,
+                    $(whatlang::Lang::$language => Language::$language), +
                 }
             }
         }
 
         impl Language {
-            pub fn code(&self) -> &'static str {
+            /// Returns the corresponding whatlang language,
+            /// or `None` if the language isn't supported by whatlang, like [`Language::Isl`].
+            pub fn to_whatlang(&self) -> Option<whatlang::Lang> {
                 match self {
-                    Language::Zho => "zho",
-                    $(Language::$language => whatlang::Lang::$language.code()), +,
-                }
-            }
-
-            pub fn from_code<S: AsRef<str>>(code: S) -> Option<Language> {
-                match code.as_ref() {
-                    "zho" => Some(Language::Zho),
-                    _ => whatlang::Lang::from_code(code.as_ref()).map(Language::from),
+                    Language::Zho => Some(whatlang::Lang::Cmn),
+                    $(Language::$language => Some(whatlang::Lang::$language)), +,
+                    _otherwise => None,
                 }
             }
         }
     };
 }
 
-make_language! {
+// languages supported by whatlang.
+make_whatlang_conversions! {
     Epo,
     Eng,
     Rus,
@@ -120,12 +213,160 @@ make_language! {
     Hye
 }
 
+/// Deprecated: use [`Language::to_whatlang`] instead, this conversion will be removed in a future release.
+///
+/// # Panics
+///
+/// Panics if the language isn't supported by whatlang, like [`Language::Isl`].
+impl From<Language> for whatlang::Lang {
+    fn from(other: Language) -> whatlang::Lang {
+        match other.to_whatlang() {
+            Some(lang) => lang,
+            None => panic!("{other:?} is not supported by whatlang"),
+        }
+    }
+}
+
+/// ISO 639-1 codes of the languages having one, used as the language subtag of the BCP 47 tags.
+const ISO_639_1: &[(Language, &str)] = &[
+    (Language::Zho, "zh"),
+    (Language::Epo, "eo"),
+    (Language::Eng, "en"),
+    (Language::Rus, "ru"),
+    (Language::Spa, "es"),
+    (Language::Por, "pt"),
+    (Language::Ita, "it"),
+    (Language::Ben, "bn"),
+    (Language::Fra, "fr"),
+    (Language::Deu, "de"),
+    (Language::Ukr, "uk"),
+    (Language::Kat, "ka"),
+    (Language::Ara, "ar"),
+    (Language::Hin, "hi"),
+    (Language::Jpn, "ja"),
+    (Language::Heb, "he"),
+    (Language::Yid, "yi"),
+    (Language::Pol, "pl"),
+    (Language::Amh, "am"),
+    (Language::Jav, "jv"),
+    (Language::Kor, "ko"),
+    (Language::Nob, "nb"),
+    (Language::Dan, "da"),
+    (Language::Swe, "sv"),
+    (Language::Fin, "fi"),
+    (Language::Tur, "tr"),
+    (Language::Nld, "nl"),
+    (Language::Hun, "hu"),
+    (Language::Ces, "cs"),
+    (Language::Ell, "el"),
+    (Language::Bul, "bg"),
+    (Language::Bel, "be"),
+    (Language::Mar, "mr"),
+    (Language::Kan, "kn"),
+    (Language::Ron, "ro"),
+    (Language::Slv, "sl"),
+    (Language::Hrv, "hr"),
+    (Language::Srp, "sr"),
+    (Language::Mkd, "mk"),
+    (Language::Lit, "lt"),
+    (Language::Lav, "lv"),
+    (Language::Est, "et"),
+    (Language::Tam, "ta"),
+    (Language::Vie, "vi"),
+    (Language::Urd, "ur"),
+    (Language::Tha, "th"),
+    (Language::Guj, "gu"),
+    (Language::Uzb, "uz"),
+    (Language::Pan, "pa"),
+    (Language::Aze, "az"),
+    (Language::Ind, "id"),
+    (Language::Tel, "te"),
+    (Language::Pes, "fa"),
+    (Language::Mal, "ml"),
+    (Language::Ori, "or"),
+    (Language::Mya, "my"),
+    (Language::Nep, "ne"),
+    (Language::Sin, "si"),
+    (Language::Khm, "km"),
+    (Language::Tuk, "tk"),
+    (Language::Aka, "ak"),
+    (Language::Zul, "zu"),
+    (Language::Sna, "sn"),
+    (Language::Afr, "af"),
+    (Language::Lat, "la"),
+    (Language::Slk, "sk"),
+    (Language::Cat, "ca"),
+    (Language::Tgl, "tl"),
+    (Language::Hye, "hy"),
+    (Language::Isl, "is"),
+    (Language::Eus, "eu"),
+    (Language::Glg, "gl"),
+    (Language::Cym, "cy"),
+    (Language::Gle, "ga"),
+    (Language::Msa, "ms"),
+    (Language::Swa, "sw"),
+    (Language::Mon, "mn"),
+];
+
+/// Deprecated ISO 639-1 codes still found in BCP 47 tags.
+const DEPRECATED_ISO_639_1: &[(Language, &str)] =
+    &[(Language::Heb, "iw"), (Language::Ind, "in"), (Language::Yid, "ji"), (Language::Nob, "no")];
+
+impl Language {
+    /// Returns the language of a BCP 47 tag like `"pt-BR"`, `"zh-Hant"` or `"sr-Latn"`.
+    ///
+    /// Only the language subtag, and the extended language subtag if any, are used,
+    /// the script, region and variant subtags are ignored.
+    /// The tag is case insensitive and its subtags can be separated by `-` or `_`.
+    pub fn from_bcp47<S: AsRef<str>>(tag: S) -> Option<Language> {
+        let mut subtags = tag.as_ref().split(['-', '_']);
+        let language = subtags.next()?.to_ascii_lowercase();
+        let subtags: Vec<_> = subtags.collect();
+        let is_valid = |subtag: &str| {
+            (1..=8).contains(&subtag.len()) && subtag.chars().all(|c| c.is_ascii_alphanumeric())
+        };
+        if !language.chars().all(|c| c.is_ascii_alphabetic())
+            || !subtags.iter().all(|s| is_valid(s))
+        {
+            return None;
+        }
+
+        // an extended language subtag, like in "zh-yue", is more specific than the language subtag.
+        let extended = subtags
+            .first()
+            .filter(|s| s.len() == 3 && s.chars().all(|c| c.is_ascii_alphabetic()))
+            .and_then(|s| Language::from_code(s.to_ascii_lowercase()));
+
+        match language.len() {
+            2 => extended.or_else(|| {
+                ISO_639_1
+                    .iter()
+                    .chain(DEPRECATED_ISO_639_1)
+                    .find(|(_, code)| *code == language)
+                    .map(|(language, _)| *language)
+            }),
+            3 => extended.or_else(|| Language::from_code(&language)),
+            _ => None,
+        }
+    }
+
+    /// Returns the BCP 47 language subtag of the language,
+    /// the ISO 639-1 code if the language has one, the ISO 639-3 code otherwise.
+    pub fn to_bcp47(&self) -> &'static str {
+        ISO_639_1
+            .iter()
+            .find(|(language, _)| language == self)
+            .map_or_else(|| self.code(), |(_, code)| code)
+    }
+}
+
 macro_rules! make_script {
     ($($script:tt), +) => {
         #[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Serialize, Deserialize, PartialOrd, Ord)]
         pub enum Script {
             $($script),+,
             Cj,
+            Lao,
             Other,
         }
 
@@ -146,12 +387,16 @@ macro_rules! make_script {
                 match self {
                     $(Script::$script => whatlang::Script::$script.name()), +,
                     Script::Cj => whatlang::Script::Mandarin.name(),
+                    Script::Lao => "Lao",
                     _other => "other",
                 }
             }
 
             pub fn from_name<S: AsRef<str>>(code: S) -> Script {
-                whatlang::Script::from_str(code.as_ref()).map(Script::from).unwrap_or_default()
+                match code.as_ref() {
+                    "Lao" => Script::Lao,
+                    code => whatlang::Script::from_str(code).map(Script::from).unwrap_or_default(),
+                }
             }
         }
     };
@@ -231,6 +476,8 @@ impl From<char> for Script {
             Script::Sinhala
         } else if chars::is_khmer(other) {
             Script::Khmer
+        } else if chars::is_lao(other) {
+            Script::Lao
         } else {
             Script::Other
         }
@@ -262,6 +509,7 @@ impl Arbitrary for Script {
             Script::Hebrew,
             Script::Kannada,
             Script::Khmer,
+            Script::Lao,
             Script::Latin,
             Script::Malayalam,
             Script::Myanmar,
@@ -348,6 +596,14 @@ impl Arbitrary for Language {
             Language::Cat,
             Language::Tgl,
             Language::Hye,
+            Language::Isl,
+            Language::Eus,
+            Language::Glg,
+            Language::Cym,
+            Language::Gle,
+            Language::Msa,
+            Language::Swa,
+            Language::Mon,
         ])
         .unwrap()
     }
@@ -355,6 +611,7 @@ impl Arbitrary for Language {
 
 #[cfg(test)]
 mod test {
+    use super::ISO_639_1;
     use crate::{Language, Script};
 
     #[test]
@@ -365,6 +622,58 @@ mod test {
         assert_eq!(Language::from_code("jpn"), Some(Language::Jpn));
         assert_eq!(Language::Cmn.code(), "cmn");
         assert_eq!(Language::from_code("cmn"), Some(Language::Cmn));
+        assert_eq!(Language::Isl.code(), "isl");
+        assert_eq!(Language::from_code("isl"), Some(Language::Isl));
+        assert_eq!(Language::from_code("xyz"), None);
+        assert_eq!(Language::Eng.to_whatlang(), Some(whatlang::Lang::Eng));
+        assert_eq!(Language::Zho.to_whatlang(), Some(whatlang::Lang::Cmn));
+        assert_eq!(Language::Cym.to_whatlang(), None);
+        assert_eq!(whatlang::Lang::from(Language::Eng), whatlang::Lang::Eng);
+        assert_eq!(Language::from(whatlang::Lang::Cmn), Language::Cmn);
+    }
+
+    #[test]
+    fn whatlang_languages_roundtrip() {
+        for lang in whatlang::Lang::all() {
+            let language = Language::from(*lang);
+            assert_eq!(language.code(), lang.code());
+            assert_eq!(language.to_whatlang(), Some(*lang));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn into_whatlang_unsupported_language() {
+        let _ = whatlang::Lang::from(Language::Isl);
+    }
+
+    #[test]
+    fn from_bcp47() {
+        assert_eq!(Language::from_bcp47("en"), Some(Language::Eng));
+        assert_eq!(Language::from_bcp47("pt-BR"), Some(Language::Por));
+        assert_eq!(Language::from_bcp47("zh-Hant"), Some(Language::Zho));
+        assert_eq!(Language::from_bcp47("zh-cmn-Hans"), Some(Language::Cmn));
+        assert_eq!(Language::from_bcp47("sr-Latn"), Some(Language::Srp));
+        assert_eq!(Language::from_bcp47("EU_es"), Some(Language::Eus));
+        assert_eq!(Language::from_bcp47("iw"), Some(Language::Heb));
+        assert_eq!(Language::from_bcp47("cmn"), Some(Language::Cmn));
+        assert_eq!(Language::from_bcp47("gle-IE"), Some(Language::Gle));
+        assert_eq!(Language::from_bcp47(""), None);
+        assert_eq!(Language::from_bcp47("xx"), None);
+        assert_eq!(Language::from_bcp47("en-"), None);
+        assert_eq!(Language::from_bcp47("english"), None);
+    }
+
+    #[test]
+    fn to_bcp47() {
+        assert_eq!(Language::Eng.to_bcp47(), "en");
+        assert_eq!(Language::Zho.to_bcp47(), "zh");
+        assert_eq!(Language::Cmn.to_bcp47(), "cmn");
+        assert_eq!(Language::Mon.to_bcp47(), "mn");
+        for (language, code) in ISO_639_1 {
+            assert_eq!(Language::from_bcp47(code), Some(*language));
+            assert_eq!(Language::from_bcp47(language.to_bcp47()), Some(*language));
+        }
     }
 
     #[test]
@@ -373,5 +682,8 @@ mod test {
         assert_eq!(Script::from_name("Latin"), Script::Latin);
         assert_eq!(Script::Cj.name(), "Mandarin");
         assert_eq!(Script::from_name("Mandarin"), Script::Cj);
+        assert_eq!(Script::Lao.name(), "Lao");
+        assert_eq!(Script::from_name("Lao"), Script::Lao);
+        assert_eq!(Script::from('ລ'), Script::Lao);
     }
 }
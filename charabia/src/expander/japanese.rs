@@ -0,0 +1,94 @@
+use unicode_normalization::UnicodeNormalization;
+use wana_kana::{ConvertJapanese, IsJapaneseChar, Options};
+
+use super::{Expander, Expansion};
+use crate::detection::{Language, Script};
+use crate::Token;
+
+/// Japanese specialized [`Expander`].
+///
+/// The [`JapaneseNormalizer`](crate::normalizer::JapaneseNormalizer) converts Katakana to Hiragana but keeps the Kanji,
+/// this Expander emits the Hiragana form of the words written with Kanji alongside their Kanji form,
+/// for instance `駄目` is expanded to `駄目` and `だめ`.
+///
+/// The Hiragana form is the reading given in the [`TokenMetadata`](crate::TokenMetadata) of the token,
+/// the tokens without a reading are not expanded,
+/// the metadata must be kept with [`TokenizerBuilder::token_metadata`](crate::TokenizerBuilder::token_metadata).
+pub struct JapaneseExpander;
+
+impl Expander for JapaneseExpander {
+    fn expand(&self, words: &[&Token], _original: &str) -> Option<Expansion> {
+        let token = words.first()?;
+        if token.script != Script::Cj || !matches!(token.language, None | Some(Language::Jpn)) {
+            return None;
+        }
+
+        if !token.lemma().chars().any(|c| c.is_kanji()) {
+            return None;
+        }
+
+        let reading = token.metadata.as_ref()?.reading.as_deref()?;
+        let hiragana =
+            reading.to_hiragana_with_opt(Options { pass_romaji: true, ..Default::default() });
+        // decompose the Hiragana form like the normalized one.
+        let hiragana: String = hiragana.nfkd().collect();
+        if hiragana == token.lemma() {
+            return None;
+        }
+
+        Some(Expansion { word_count: 1, alternatives: vec![vec![hiragana]] })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::borrow::Cow::Owned;
+
+    use super::JapaneseExpander;
+    use crate::expander::{Expander, Expansion};
+    use crate::{Language, Script, Token, TokenKind, TokenMetadata};
+
+    fn token(lemma: &str, reading: Option<&str>) -> Token<'static> {
+        Token {
+            kind: TokenKind::Word,
+            lemma: Owned(lemma.to_string()),
+            char_end: lemma.chars().count(),
+            byte_end: lemma.len(),
+            script: Script::Cj,
+            language: Some(Language::Jpn),
+            metadata: Some(Box::new(TokenMetadata {
+                reading: reading.map(str::to_string),
+                ..Default::default()
+            })),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn kanji_alternative() {
+        let token = token("駄目", Some("ダメ"));
+        let expansion = JapaneseExpander.expand(&[&token], "駄目");
+
+        assert_eq!(
+            expansion,
+            Some(Expansion {
+                word_count: 1, alternatives: vec![vec!["た\u{3099}め".to_string()]]
+            })
+        );
+    }
+
+    #[test]
+    fn kana_without_alternative() {
+        let token = token("た\u{3099}め", Some("ダメ"));
+        assert_eq!(JapaneseExpander.expand(&[&token], "ダメ"), None);
+    }
+
+    #[test]
+    fn kanji_without_reading() {
+        let token = token("駄目", None);
+        assert_eq!(JapaneseExpander.expand(&[&token], "駄目"), None);
+
+        let token = Token { metadata: None, ..token };
+        assert_eq!(JapaneseExpander.expand(&[&token], "駄目"), None);
+    }
+}
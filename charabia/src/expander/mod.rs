@@ -0,0 +1,278 @@
+use std::borrow::Cow;
+use std::collections::VecDeque;
+use std::fmt;
+
+#[cfg(feature = "japanese-transliteration")]
+pub use self::japanese::JapaneseExpander;
+pub use self::synonym::SynonymExpander;
+use crate::normalizer::NormalizedTokenIter;
+use crate::{SeparatorKind, Token, TokenKind};
+
+#[cfg(feature = "japanese-transliteration")]
+mod japanese;
+mod synonym;
+
+/// Trait defining an expander.
+///
+/// An expander is applied after the normalization and emits additional [`Token`]s,
+/// called alternatives, at the same position as the normalized ones.
+pub trait Expander: Sync + Send {
+    /// Returns the alternatives of the words starting the provided slice, if any.
+    ///
+    /// `words` contains the normalized word [`Token`]s following each other in the text,
+    /// only separated by soft separators, and never contains more than [`Expander::max_words`] words.
+    /// `original` is the whole text being tokenized, the byte indexes of the tokens point into it.
+    ///
+    /// An [`Expansion`] matching no word or more words than provided is ignored.
+    fn expand(&self, words: &[&Token], original: &str) -> Option<Expansion>;
+
+    /// Returns the maximum number of words the expander can match at once.
+    fn max_words(&self) -> usize {
+        1
+    }
+}
+
+/// Alternatives returned by an [`Expander`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Expansion {
+    /// number of words matched by the expansion.
+    pub word_count: usize,
+    /// list of alternatives, each alternative is a list of words.
+    pub alternatives: Vec<Vec<String>>,
+}
+
+/// Structure for providing options to the expansion stage.
+#[derive(Clone, Copy, Default)]
+pub struct ExpanderOption<'tb> {
+    pub expanders: &'tb [Box<dyn Expander>],
+}
+
+impl fmt::Debug for ExpanderOption<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ExpanderOption").field("expanders", &self.expanders.len()).finish()
+    }
+}
+
+/// Iterator over normalized [`Token`]s and their alternatives.
+///
+/// Each alternative spans the same bytes and characters as the tokens it replaces,
+/// has a `position_increment` of `0` when it shares the position of the previous token,
+/// and has a `position_length` covering the replaced tokens, allowing to build a token graph.
+/// The replaced tokens keep their `position_increment`, separators included.
+pub struct ExpandedTokenIter<'o, 'aho, 'lang, 'tb> {
+    token_iter: NormalizedTokenIter<'o, 'aho, 'lang, 'tb>,
+    original: &'o str,
+    options: ExpanderOption<'tb>,
+    max_words: usize,
+    /// tokens read from `token_iter` but not expanded yet.
+    lookahead: VecDeque<Token<'o>>,
+    /// expanded tokens ready to be returned.
+    expanded: VecDeque<Token<'o>>,
+}
+
+impl<'o, 'aho, 'lang, 'tb> NormalizedTokenIter<'o, 'aho, 'lang, 'tb> {
+    /// Expand normalized [`Token`]s using the provided [`Expander`]s.
+    ///
+    /// `original` must be the text the tokens come from.
+    pub fn expand(
+        self,
+        original: &'o str,
+        options: ExpanderOption<'tb>,
+    ) -> ExpandedTokenIter<'o, 'aho, 'lang, 'tb> {
+        let max_words = options.expanders.iter().map(|e| e.max_words()).max().unwrap_or(0);
+        ExpandedTokenIter {
+            token_iter: self,
+            original,
+            options,
+            max_words,
+            lookahead: VecDeque::new(),
+            expanded: VecDeque::new(),
+        }
+    }
+}
+
+impl<'o> Iterator for ExpandedTokenIter<'o, '_, '_, '_> {
+    type Item = Token<'o>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(token) = self.expanded.pop_front() {
+            return Some(token);
+        }
+
+        let token = self.lookahead.pop_front().or_else(|| self.token_iter.next())?;
+        if self.max_words == 0 || !is_expandable(&token) {
+            return Some(token);
+        }
+
+        // read enough tokens to let the expanders match several words.
+        let mut words = 1 + self.lookahead.iter().filter(|t| is_expandable(t)).count();
+        while words < self.max_words && self.lookahead.back().is_none_or(can_continue) {
+            match self.token_iter.next() {
+                Some(next) => {
+                    words += is_expandable(&next) as usize;
+                    self.lookahead.push_back(next);
+                }
+                None => break,
+            }
+        }
+
+        let Some(expansion) = self.expansion(&token) else {
+            return Some(token);
+        };
+
+        // take the tokens replaced by the alternatives out of the lookahead.
+        let mut replaced = vec![token];
+        let mut remaining_words = expansion.word_count - 1;
+        while remaining_words > 0 {
+            let Some(next) = self.lookahead.pop_front() else { break };
+            remaining_words -= is_expandable(&next) as usize;
+            replaced.push(next);
+        }
+
+        self.expanded = graph(replaced, expansion.alternatives);
+        self.expanded.pop_front()
+    }
+}
+
+impl ExpandedTokenIter<'_, '_, '_, '_> {
+    /// Returns the longest expansion starting with the provided token,
+    /// merging the alternatives of the expanders matching the same number of words.
+    fn expansion(&self, token: &Token) -> Option<Expansion> {
+        let words: Vec<&Token> = std::iter::once(token)
+            .chain(self.lookahead.iter().take_while(|t| can_continue(t)))
+            .filter(|t| is_expandable(t))
+            .take(self.max_words)
+            .collect();
+
+        let mut best: Option<Expansion> = None;
+        for expander in self.options.expanders {
+            let words = &words[..words.len().min(expander.max_words())];
+            let Some(expansion) = expander.expand(words, self.original) else { continue };
+            if expansion.word_count == 0 || expansion.word_count > words.len() {
+                continue;
+            }
+            match best.as_mut() {
+                Some(best) if best.word_count == expansion.word_count => {
+                    best.alternatives.extend(expansion.alternatives);
+                }
+                Some(best) if best.word_count > expansion.word_count => (),
+                _otherwise => best = Some(expansion),
+            }
+        }
+
+        let mut expansion = best?;
+        let matched: Vec<&str> = words[..expansion.word_count].iter().map(|t| t.lemma()).collect();
+        expansion
+            .alternatives
+            .retain(|alternative| !alternative.is_empty() && *alternative != matched);
+        expansion.alternatives.dedup();
+
+        Some(expansion).filter(|e| !e.alternatives.is_empty())
+    }
+}
+
+/// Returns true if the token can be part of an expansion.
+fn is_expandable(token: &Token) -> bool {
+    matches!(token.kind, TokenKind::Word | TokenKind::StopWord)
+}
+
+/// Returns true if an expansion can continue after the token.
+fn can_continue(token: &Token) -> bool {
+    token.separator_kind() != Some(SeparatorKind::Hard)
+}
+
+/// Orders the replaced tokens and their alternatives by position,
+/// setting the position increment and length of each alternative.
+///
+/// The replaced tokens keep their position increment, the words of an alternative take the positions of the replaced words
+/// and the last word of an alternative spans the remaining positions of the replaced tokens.
+/// An alternative having more words than the replaced tokens extends the graph after them,
+/// the last replaced token then spans these additional positions too.
+fn graph<'o>(replaced: Vec<Token<'o>>, alternatives: Vec<Vec<String>>) -> VecDeque<Token<'o>> {
+    let first = &replaced[0];
+    let last = &replaced[replaced.len() - 1];
+    let words_len = replaced.iter().filter(|token| is_expandable(token)).count();
+    let template = Token {
+        kind: TokenKind::Word,
+        char_start: first.char_start,
+        char_end: last.char_end,
+        byte_start: first.byte_start,
+        byte_end: last.byte_end,
+        script: first.script,
+        language: first.language,
+        language_hinted: first.language_hinted,
+        chinese_variant: first.chinese_variant,
+        // the metadata of a single word still describes its alternatives.
+        metadata: if words_len == 1 { first.metadata.clone() } else { None },
+        ..Default::default()
+    };
+
+    let first_increment = first.position_increment;
+
+    // position of each replaced token relative to the first one.
+    let mut offset = 0;
+    let mut replaced: Vec<(usize, Token<'o>)> = replaced
+        .into_iter()
+        .enumerate()
+        .map(|(index, token)| {
+            if index > 0 {
+                offset += token.position_increment;
+            }
+            (offset, token)
+        })
+        .collect();
+    let word_offsets: Vec<usize> = replaced
+        .iter()
+        .filter(|(_, token)| is_expandable(token))
+        .map(|(offset, _)| *offset)
+        .collect();
+    let replaced_len =
+        offset + replaced.last().map_or(1, |(_, token)| token.position_length.max(1));
+    let length = alternatives
+        .iter()
+        .map(|alternative| replaced_len + alternative.len().saturating_sub(words_len))
+        .fold(replaced_len, usize::max);
+
+    // the positions following the replaced words are appended after the replaced tokens.
+    let word_offset = |index: usize| match word_offsets.get(index) {
+        Some(offset) => *offset,
+        None => replaced_len + index - words_len,
+    };
+
+    if let Some((_, token)) = replaced.last_mut() {
+        token.position_length = token.position_length.max(1) + length - replaced_len;
+    }
+
+    let mut tokens: Vec<(usize, Token<'o>)> = replaced;
+    for alternative in alternatives {
+        let alternative_len = alternative.len();
+        for (index, word) in alternative.into_iter().enumerate() {
+            let offset = word_offset(index);
+            let end = if index + 1 == alternative_len { length } else { word_offset(index + 1) };
+            tokens.push((
+                offset,
+                Token {
+                    lemma: Cow::Owned(word),
+                    position_length: end - offset,
+                    ..template.clone()
+                },
+            ));
+        }
+    }
+
+    // the sort is stable, the replaced tokens stay before the alternatives at the same position.
+    tokens.sort_by_key(|(offset, _)| *offset);
+
+    let mut previous = None;
+    tokens
+        .into_iter()
+        .map(|(offset, mut token)| {
+            token.position_increment = match previous {
+                Some(previous) => offset - previous,
+                None => first_increment,
+            };
+            previous = Some(offset);
+            token
+        })
+        .collect()
+}
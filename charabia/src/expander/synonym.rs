@@ -0,0 +1,249 @@
+use std::collections::HashMap;
+
+use super::{Expander, Expansion};
+use crate::Token;
+
+/// An [`Expander`] emitting the synonyms of words and expressions.
+///
+/// Expressions are made of words separated by whitespaces, for instance `nyc` can be expanded to `new york`
+/// and `new york` to `nyc`.
+/// Expressions are compared to the normalized lemmas of the tokens, so they should be given normalized too,
+/// for instance lowercased.
+///
+/// # Example
+///
+/// ```
+/// use charabia::expander::{Expander, SynonymExpander};
+/// use charabia::TokenizerBuilder;
+///
+/// let mut synonyms = SynonymExpander::new();
+/// synonyms.insert_equivalent(&["nyc", "new york"]);
+/// let expanders: Vec<Box<dyn Expander>> = vec![Box::new(synonyms)];
+///
+/// let mut builder = TokenizerBuilder::default();
+/// builder.expanders(&expanders);
+/// let tokenizer = builder.build();
+///
+/// let text = "nyc";
+/// let output: Vec<_> = tokenizer
+///     .tokenize_expanded(text)
+///     .map(|t| (t.lemma().to_string(), t.position_increment, t.position_length))
+///     .collect();
+/// assert_eq!(
+///     output,
+///     [("nyc".to_string(), 1, 2), ("new".to_string(), 0, 1), ("york".to_string(), 1, 1)]
+/// );
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct SynonymExpander {
+    synonyms: HashMap<String, Vec<Vec<String>>>,
+    max_words: usize,
+}
+
+impl SynonymExpander {
+    /// Creates an expander without any synonym.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds `synonym` as an alternative of `expression`.
+    ///
+    /// `synonym` is not expanded to `expression`, see [`SynonymExpander::insert_equivalent`] for that.
+    pub fn insert(&mut self, expression: &str, synonym: &str) -> &mut Self {
+        let words: Vec<_> = expression.split_whitespace().collect();
+        let synonym: Vec<_> = synonym.split_whitespace().map(str::to_string).collect();
+        if words.is_empty() || synonym.is_empty() {
+            return self;
+        }
+
+        self.max_words = self.max_words.max(words.len());
+        let alternatives = self.synonyms.entry(words.join(" ")).or_default();
+        if !alternatives.contains(&synonym) {
+            alternatives.push(synonym);
+        }
+
+        self
+    }
+
+    /// Adds expressions that are all alternatives of each other.
+    pub fn insert_equivalent(&mut self, expressions: &[&str]) -> &mut Self {
+        for expression in expressions {
+            for synonym in expressions.iter().filter(|synonym| synonym != &expression) {
+                self.insert(expression, synonym);
+            }
+        }
+
+        self
+    }
+}
+
+impl Expander for SynonymExpander {
+    fn expand(&self, words: &[&Token], _original: &str) -> Option<Expansion> {
+        // try the longest expressions first.
+        (1..=words.len().min(self.max_words)).rev().find_map(|word_count| {
+            let lemmas: Vec<_> = words[..word_count].iter().map(|t| t.lemma()).collect();
+            let alternatives = self.synonyms.get(&lemmas.join(" "))?;
+            Some(Expansion { word_count, alternatives: alternatives.clone() })
+        })
+    }
+
+    fn max_words(&self) -> usize {
+        self.max_words
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+
+    use super::SynonymExpander;
+    use crate::expander::{Expander, ExpanderOption, Expansion};
+    use crate::{Language, Script, Token, TokenizerBuilder};
+
+    fn expanded(text: &str, expanders: &[Box<dyn Expander>]) -> Vec<(String, usize, usize)> {
+        let mut builder = TokenizerBuilder::default();
+        builder.expanders(expanders);
+        let tokenizer = builder.build();
+
+        tokenizer
+            .tokenize_expanded(text)
+            .map(|t| (t.lemma().to_string(), t.position_increment, t.position_length))
+            .collect()
+    }
+
+    fn synonyms() -> Vec<Box<dyn Expander>> {
+        let mut synonyms = SynonymExpander::new();
+        synonyms.insert_equivalent(&["nyc", "new york"]).insert("car", "automobile");
+        vec![Box::new(synonyms)]
+    }
+
+    #[test]
+    fn single_word_to_multi_word() {
+        let output = expanded("NYC is big", &synonyms());
+        assert_eq!(
+            output,
+            [
+                ("nyc".to_string(), 1, 2),
+                ("new".to_string(), 0, 1),
+                ("york".to_string(), 1, 1),
+                (" ".to_string(), 1, 1),
+                ("is".to_string(), 1, 1),
+                (" ".to_string(), 1, 1),
+                ("big".to_string(), 1, 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn multi_word_to_single_word() {
+        let output = expanded("New York.", &synonyms());
+        assert_eq!(
+            output,
+            [
+                ("new".to_string(), 1, 1),
+                ("nyc".to_string(), 0, 3),
+                (" ".to_string(), 1, 1),
+                ("york".to_string(), 1, 1),
+                (".".to_string(), 1, 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn multi_word_to_multi_word() {
+        let mut synonyms = SynonymExpander::new();
+        synonyms.insert("new york", "big apple");
+        let expanders: Vec<Box<dyn Expander>> = vec![Box::new(synonyms)];
+
+        let output = expanded("new york city", &expanders);
+        assert_eq!(
+            output,
+            [
+                ("new".to_string(), 1, 1),
+                ("big".to_string(), 0, 2),
+                (" ".to_string(), 1, 1),
+                ("york".to_string(), 1, 1),
+                ("apple".to_string(), 0, 1),
+                (" ".to_string(), 1, 1),
+                ("city".to_string(), 1, 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn one_way_synonym() {
+        let output = expanded("car automobile", &synonyms());
+        assert_eq!(
+            output,
+            [
+                ("car".to_string(), 1, 1),
+                ("automobile".to_string(), 0, 1),
+                (" ".to_string(), 1, 1),
+                ("automobile".to_string(), 1, 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn alternatives_share_offsets() {
+        let mut builder = TokenizerBuilder::default();
+        let expanders = synonyms();
+        builder.expanders(&expanders);
+        let tokenizer = builder.build();
+
+        let text = "I love New York";
+        let tokens: Vec<_> = tokenizer.tokenize_expanded(text).collect();
+        let nyc = tokens.iter().find(|t| t.lemma() == "nyc").unwrap();
+        assert_eq!(&text[nyc.byte_start..nyc.byte_end], "New York");
+        assert_eq!(nyc.char_start, 7);
+        assert_eq!(nyc.char_end, 15);
+    }
+
+    #[test]
+    fn hard_separator_breaks_expression() {
+        let output = expanded("new. york", &synonyms());
+        assert!(output.iter().all(|(lemma, _, _)| lemma != "nyc"));
+    }
+
+    #[test]
+    fn no_expanders() {
+        let output = expanded("nyc", &[]);
+        assert_eq!(output, [("nyc".to_string(), 1, 1)]);
+    }
+
+    #[test]
+    fn invalid_expansions_are_ignored() {
+        struct InvalidExpander(usize);
+
+        impl Expander for InvalidExpander {
+            fn expand(&self, _words: &[&Token], _original: &str) -> Option<Expansion> {
+                Some(Expansion { word_count: self.0, alternatives: vec![vec!["a".to_string()]] })
+            }
+
+            fn max_words(&self) -> usize {
+                2
+            }
+        }
+
+        let expanders: Vec<Box<dyn Expander>> =
+            vec![Box::new(InvalidExpander(0)), Box::new(InvalidExpander(3))];
+        let output = expanded("new york", &expanders);
+        assert_eq!(
+            output,
+            [("new".to_string(), 1, 1), (" ".to_string(), 1, 1), ("york".to_string(), 1, 1)]
+        );
+    }
+
+    #[test]
+    fn alternatives_keep_language_hint() {
+        let tokenizer = TokenizerBuilder::default().into_tokenizer();
+        let expanders = synonyms();
+        let options = ExpanderOption { expanders: &expanders };
+
+        let languages = HashMap::from([(Script::Latin, Language::Eng)]);
+        let tokens: Vec<_> =
+            tokenizer.tokenize_with_language("nyc", &languages).expand("nyc", options).collect();
+        assert_eq!(tokens.len(), 3);
+        assert!(tokens.iter().all(|t| t.language == Some(Language::Eng) && t.language_hinted));
+    }
+}
@@ -48,6 +48,7 @@ extern crate quickcheck;
 #[macro_use(quickcheck)]
 extern crate quickcheck_macros;
 
+pub mod expander;
 pub mod normalizer;
 pub mod segmenter;
 pub mod separators;
@@ -56,11 +57,17 @@ mod detection;
 mod token;
 mod tokenizer;
 
-pub use detection::{Language, Script, StrDetection};
+pub use detection::{
+    ChineseVariant, Language, LanguageDetector, NgramDetector, Script, StrDetection,
+    WhatlangDetector, RELIABLE_CONFIDENCE,
+};
 pub use normalizer::Normalize;
 pub use segmenter::Segment;
 #[cfg(test)]
 pub use token::StaticToken;
-pub use token::{SeparatorKind, Token, TokenKind};
+pub use token::{CompactToken, SeparatorKind, Token, TokenKind, TokenMetadata};
 
-pub use crate::tokenizer::{ReconstructedTokenIter, Tokenize, Tokenizer, TokenizerBuilder};
+pub use crate::tokenizer::{
+    ReaderReconstructedTokenIter, ReaderTokenIter, ReconstructedTokenIter, Tokenize, Tokenizer,
+    TokenizerBuilder,
+};
@@ -1,8 +1,9 @@
 #[cfg(feature = "chinese-normalization-pinyin")]
 use pinyin::ToPinyin;
 
-use super::CharNormalizer;
-use crate::detection::{Language, Script};
+use super::{CharNormalizer, Normalizer, NormalizerOption};
+use crate::detection::chinese::SIMPLIFIED_FORMS;
+use crate::detection::{ChineseVariant, Language, Script};
 use crate::normalizer::CharOrStr;
 use crate::Token;
 
@@ -10,10 +11,46 @@ use crate::Token;
 /// 1. convert Z, Simplified, Semantic, Old, and Wrong variants
 /// 2. converting them into Pinyin characters
 ///
+/// The variants are folded to the Traditional form by default,
+/// use [`ChineseNormalizer::new`] to fold them to the Simplified form instead.
+///
 /// This Normalizer uses [`pinyin`] internally to normalize the provided token.
-pub struct ChineseNormalizer;
+#[derive(Debug, Clone, Copy)]
+pub struct ChineseNormalizer {
+    fold: ChineseVariant,
+}
+
+impl ChineseNormalizer {
+    /// Creates a normalizer folding the Chinese variants to the given [`ChineseVariant`].
+    pub fn new(fold: ChineseVariant) -> Self {
+        Self { fold }
+    }
+}
+
+impl Default for ChineseNormalizer {
+    fn default() -> Self {
+        Self::new(ChineseVariant::Traditional)
+    }
+}
+
+impl Normalizer for ChineseNormalizer {
+    fn normalize<'o>(&self, token: Token<'o>, options: &NormalizerOption) -> Token<'o> {
+        match self.fold {
+            ChineseVariant::Traditional => ChineseCharNormalizer::<false>.normalize(token, options),
+            ChineseVariant::Simplified => ChineseCharNormalizer::<true>.normalize(token, options),
+        }
+    }
+
+    fn should_normalize(&self, token: &Token) -> bool {
+        token.script == Script::Cj
+            && matches!(token.language, None | Some(Language::Cmn | Language::Zho))
+    }
+}
 
-impl CharNormalizer for ChineseNormalizer {
+/// Character normalizer of the [`ChineseNormalizer`], folding to the Simplified form if `SIMPLIFIED`.
+struct ChineseCharNormalizer<const SIMPLIFIED: bool>;
+
+impl<const SIMPLIFIED: bool> CharNormalizer for ChineseCharNormalizer<SIMPLIFIED> {
     fn normalize_char(&self, c: char) -> Option<CharOrStr> {
         // Normalize Z, Simplified, Semantic, Old, and Wrong variants
         let kvariant = match irg_kvariants::KVARIANTS.get(&c) {
@@ -21,6 +58,13 @@ impl CharNormalizer for ChineseNormalizer {
             None => c,
         };
 
+        // The kVariants fold the Simplified variants to the Traditional form,
+        // fold them back to the Simplified form if asked.
+        let kvariant = match SIMPLIFIED_FORMS.get(&kvariant) {
+            Some(simplified) if SIMPLIFIED => *simplified,
+            _ => kvariant,
+        };
+
         // Normalize to Pinyin
         // If we don't manage to convert the kvariant, we try to convert the original character.
         // If none of them are converted, we return the kvariant.
@@ -38,8 +82,7 @@ impl CharNormalizer for ChineseNormalizer {
     }
 
     fn should_normalize(&self, token: &Token) -> bool {
-        token.script == Script::Cj
-            && matches!(token.language, None | Some(Language::Cmn) | Some(Language::Zho))
+        Normalizer::should_normalize(&ChineseNormalizer::default(), token)
     }
 }
 
@@ -47,7 +90,7 @@ impl CharNormalizer for ChineseNormalizer {
 mod test {
     use std::borrow::Cow::Owned;
 
-    use crate::normalizer::test::test_normalizer;
+    use crate::normalizer::test::{test_normalizer, TEST_NORMALIZER_OPTIONS};
     use crate::normalizer::{Normalizer, NormalizerOption};
     use crate::token::TokenKind;
 
@@ -203,6 +246,7 @@ mod test {
                 char_map: Some(vec![(3, 3), (3, 3)]),
                 script: Script::Cj,
                 language: Some(Language::Cmn),
+                ..Default::default()
             },
             Token {
                 kind: TokenKind::Word,
@@ -214,6 +258,7 @@ mod test {
                 char_map: Some(vec![(3, 3), (3, 3), (3, 3), (3, 3)]),
                 script: Script::Cj,
                 language: Some(Language::Cmn),
+                ..Default::default()
             },
             Token {
                 kind: TokenKind::Word,
@@ -225,9 +270,45 @@ mod test {
                 char_map: Some(vec![(3, 3), (3, 3), (3, 3), (3, 3), (3, 3), (3, 4)]),
                 script: Script::Cj,
                 language: Some(Language::Zho),
+                ..Default::default()
             },
         ]
     }
 
-    test_normalizer!(ChineseNormalizer, tokens(), normalizer_result(), normalized_tokens());
+    test_normalizer!(
+        ChineseNormalizer::default(),
+        tokens(),
+        normalizer_result(),
+        normalized_tokens()
+    );
+
+    #[cfg(not(feature = "chinese-normalization-pinyin"))]
+    #[test]
+    fn fold_to_simplified() {
+        let normalizer = ChineseNormalizer::new(ChineseVariant::Simplified);
+
+        let token = Token {
+            lemma: Owned("東亞亚亜".to_string()),
+            char_end: 4,
+            byte_end: 12,
+            script: Script::Cj,
+            language: Some(Language::Cmn),
+            chinese_variant: Some(ChineseVariant::Traditional),
+            ..Default::default()
+        };
+        let token = normalizer.normalize(token, &TEST_NORMALIZER_OPTIONS);
+        assert_eq!(token.lemma(), "东亚亚亚");
+
+        let token = Token {
+            lemma: Owned("东亚".to_string()),
+            char_end: 2,
+            byte_end: 6,
+            script: Script::Cj,
+            language: Some(Language::Cmn),
+            chinese_variant: Some(ChineseVariant::Simplified),
+            ..Default::default()
+        };
+        let token = ChineseNormalizer::default().normalize(token, &TEST_NORMALIZER_OPTIONS);
+        assert_eq!(token.lemma(), "東亞");
+    }
 }
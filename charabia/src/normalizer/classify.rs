@@ -13,8 +13,11 @@ use crate::{SeparatorKind, Token, TokenKind};
 /// [`TokenKind`]: crate::TokenKind
 ///
 /// Any `Token` that is in the stop words [`Set`] is assigned to [`TokenKind::StopWord`].
+/// Any `Token` that is not a separator and whose part of speech, given by the segmenter in its [`TokenMetadata`],
+/// starts with one of the stop parts of speech is assigned to [`TokenKind::StopWord`] too.
 ///
 /// [`TokenKind::StopWord`]: crate::TokenKind#StopWord
+/// [`TokenMetadata`]: crate::TokenMetadata
 pub struct Classifier;
 
 impl Normalizer for Classifier {
@@ -39,6 +42,10 @@ impl Normalizer for Classifier {
             _otherwise => (),
         }
 
+        if token.kind == TokenKind::Word && is_stop_part_of_speech(&token, options) {
+            token.kind = TokenKind::StopWord;
+        }
+
         token
     }
 
@@ -49,9 +56,38 @@ impl Normalizer for Classifier {
 
 /// Structure for providing options to the classfier.
 #[derive(Debug, Clone, Default)]
+#[non_exhaustive]
 pub struct ClassifierOption<'no> {
     pub stop_words: Option<Set<&'no [u8]>>,
     pub separators: Option<&'no [&'no str]>,
+    /// parts of speech of the stop words, see [`JAPANESE_STOP_PARTS_OF_SPEECH`] and [`KOREAN_STOP_PARTS_OF_SPEECH`].
+    pub stop_parts_of_speech: Option<&'no [&'no str]>,
+}
+
+impl ClassifierOption<'_> {
+    /// Creates the options of a classifier without any stop word or custom separator.
+    pub const fn new() -> Self {
+        Self { stop_words: None, separators: None, stop_parts_of_speech: None }
+    }
+}
+
+/// Parts of speech of the Japanese particles, auxiliary verbs and symbols, in both IPADIC and UniDic.
+pub const JAPANESE_STOP_PARTS_OF_SPEECH: &[&str] = &["助詞", "助動詞", "記号", "補助記号"];
+
+/// Parts of speech of the Korean particles, auxiliary verbs and symbols in ko-dic,
+/// `J` matches all the particles, like `JKS` or `JX`.
+pub const KOREAN_STOP_PARTS_OF_SPEECH: &[&str] = &["J", "VX", "SF", "SP", "SS", "SE", "SO", "SW"];
+
+fn is_stop_part_of_speech(token: &Token, options: &NormalizerOption) -> bool {
+    let Some(stop_parts_of_speech) = options.classifier.stop_parts_of_speech else {
+        return false;
+    };
+
+    token
+        .metadata
+        .as_ref()
+        .and_then(|metadata| metadata.part_of_speech.as_deref())
+        .is_some_and(|pos| stop_parts_of_speech.iter().any(|stop| pos.starts_with(stop)))
 }
 
 fn separator_kind(lemma: &str) -> SeparatorKind {
@@ -73,6 +109,7 @@ mod test {
     use std::borrow::Cow;
 
     use crate::normalizer::test::test_normalizer;
+    use crate::TokenMetadata;
 
     // base tokens to normalize.
     fn tokens() -> Vec<Token<'static>> {
@@ -173,8 +210,12 @@ mod test {
         let stop_words = Set::new(stop_words).unwrap();
         let options = NormalizerOption {
             create_char_map: true,
-            classifier: ClassifierOption { stop_words: Some(stop_words), separators: None },
+            classifier: ClassifierOption {
+                stop_words: Some(stop_words),
+                ..ClassifierOption::new()
+            },
             lossy: false,
+            ..NormalizerOption::new()
         };
 
         let token = Classifier
@@ -190,6 +231,36 @@ mod test {
         assert!(token.is_word());
     }
 
+    #[test]
+    fn stop_parts_of_speech() {
+        let options = NormalizerOption {
+            create_char_map: true,
+            classifier: ClassifierOption {
+                stop_parts_of_speech: Some(KOREAN_STOP_PARTS_OF_SPEECH),
+                ..ClassifierOption::new()
+            },
+            lossy: false,
+            ..NormalizerOption::new()
+        };
+        let token = |lemma, part_of_speech: &str| Token {
+            lemma: Cow::Borrowed(lemma),
+            metadata: Some(Box::new(TokenMetadata {
+                part_of_speech: Some(part_of_speech.to_string()),
+                ..Default::default()
+            })),
+            ..Default::default()
+        };
+
+        assert!(Classifier.normalize(token("의", "JKG"), &options).is_stopword());
+        assert!(Classifier.normalize(token("습니다", "VX"), &options).is_stopword());
+        assert!(Classifier.normalize(token("한국어", "NNG"), &options).is_word());
+        // separators stay separators.
+        assert!(Classifier.normalize(token(".", "SF"), &options).is_separator());
+        // tokens without metadata are words.
+        let token = Token { lemma: Cow::Borrowed("의"), ..Default::default() };
+        assert!(Classifier.normalize(token, &options).is_word());
+    }
+
     #[quickcheck]
     fn is_stop_word_iff_stop_words_contain_lemma(
         mut stop_words: Vec<String>,
@@ -210,8 +281,12 @@ mod test {
         let stop_words = Set::new(stop_words).unwrap();
         let options = NormalizerOption {
             create_char_map,
-            classifier: ClassifierOption { stop_words: Some(stop_words), separators: None },
+            classifier: ClassifierOption {
+                stop_words: Some(stop_words),
+                ..ClassifierOption::new()
+            },
             lossy,
+            ..NormalizerOption::new()
         };
 
         let token = Classifier
@@ -235,8 +310,12 @@ mod test {
         let separators: Vec<&str> = separators.iter().map(|s| s.as_str()).collect();
         let options = NormalizerOption {
             create_char_map,
-            classifier: ClassifierOption { stop_words: None, separators: Some(&separators) },
+            classifier: ClassifierOption {
+                separators: Some(&separators),
+                ..ClassifierOption::new()
+            },
             lossy,
+            ..NormalizerOption::new()
         };
 
         let token = Classifier
@@ -265,8 +344,10 @@ mod test {
             classifier: ClassifierOption {
                 stop_words: Some(stop_words),
                 separators: Some(&separators),
+                ..ClassifierOption::new()
             },
             lossy,
+            ..NormalizerOption::new()
         };
 
         let token = Classifier
@@ -0,0 +1,183 @@
+use super::{CharNormalizer, CharOrStr};
+use crate::{Script, Token};
+
+/// Devanagari specialized [`Normalizer`].
+///
+/// Hindi, Marathi and Nepali texts are normalized by:
+/// - folding the nukta forms into their base consonant: 'क़' to 'क', 'ज़' to 'ज'
+/// - converting the chandrabindu into an anusvara: 'ँ' to 'ं'
+/// - removing the zero-width joiners and non-joiners that only change the rendering of the conjuncts
+///
+/// [`Normalizer`]: super::Normalizer
+pub struct DevanagariNormalizer;
+
+impl CharNormalizer for DevanagariNormalizer {
+    fn normalize_char(&self, c: char) -> Option<CharOrStr> {
+        match c {
+            // nukta and joiners
+            '\u{093C}' | '\u{200C}' | '\u{200D}' => None,
+            // chandrabindu
+            '\u{0901}' => Some('\u{0902}'.into()),
+            // precomposed nukta forms
+            '\u{0929}' => Some('\u{0928}'.into()),
+            '\u{0931}' => Some('\u{0930}'.into()),
+            '\u{0934}' => Some('\u{0933}'.into()),
+            '\u{0958}' => Some('\u{0915}'.into()),
+            '\u{0959}' => Some('\u{0916}'.into()),
+            '\u{095A}' => Some('\u{0917}'.into()),
+            '\u{095B}' => Some('\u{091C}'.into()),
+            '\u{095C}' => Some('\u{0921}'.into()),
+            '\u{095D}' => Some('\u{0922}'.into()),
+            '\u{095E}' => Some('\u{092B}'.into()),
+            '\u{095F}' => Some('\u{092F}'.into()),
+            _ => Some(c.into()),
+        }
+    }
+
+    fn should_normalize(&self, token: &Token) -> bool {
+        token.script == Script::Devanagari && token.lemma().chars().any(is_should_normalize)
+    }
+}
+
+fn is_should_normalize(c: char) -> bool {
+    matches!(
+        c,
+        '\u{093C}'
+            | '\u{200C}'
+            | '\u{200D}'
+            | '\u{0901}'
+            | '\u{0929}'
+            | '\u{0931}'
+            | '\u{0934}'
+            | '\u{0958}'..='\u{095F}'
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use std::borrow::Cow::Owned;
+
+    use crate::normalizer::test::test_normalizer;
+    use crate::normalizer::{Normalizer, NormalizerOption};
+    use crate::token::TokenKind;
+
+    // base tokens to normalize.
+    fn tokens() -> Vec<Token<'static>> {
+        vec![
+            // precomposed nukta form
+            Token {
+                lemma: Owned("\u{0958}लम".to_string()),
+                char_end: 3,
+                byte_end: 9,
+                script: Script::Devanagari,
+                ..Default::default()
+            },
+            // decomposed nukta form
+            Token {
+                lemma: Owned("फ\u{093C}ोन".to_string()),
+                char_end: 4,
+                byte_end: 12,
+                script: Script::Devanagari,
+                ..Default::default()
+            },
+            // chandrabindu
+            Token {
+                lemma: Owned("हू\u{0901}".to_string()),
+                char_end: 3,
+                byte_end: 9,
+                script: Script::Devanagari,
+                ..Default::default()
+            },
+            // conjunct with a zero-width joiner
+            Token {
+                lemma: Owned("क्\u{200D}ष".to_string()),
+                char_end: 4,
+                byte_end: 12,
+                script: Script::Devanagari,
+                ..Default::default()
+            },
+        ]
+    }
+
+    // expected result of the current Normalizer.
+    fn normalizer_result() -> Vec<Token<'static>> {
+        vec![
+            Token {
+                lemma: Owned("कलम".to_string()),
+                char_end: 3,
+                byte_end: 9,
+                char_map: Some(vec![(3, 3), (3, 3), (3, 3)]),
+                script: Script::Devanagari,
+                ..Default::default()
+            },
+            Token {
+                lemma: Owned("फोन".to_string()),
+                char_end: 4,
+                byte_end: 12,
+                char_map: Some(vec![(3, 3), (3, 0), (3, 3), (3, 3)]),
+                script: Script::Devanagari,
+                ..Default::default()
+            },
+            Token {
+                lemma: Owned("हूं".to_string()),
+                char_end: 3,
+                byte_end: 9,
+                char_map: Some(vec![(3, 3), (3, 3), (3, 3)]),
+                script: Script::Devanagari,
+                ..Default::default()
+            },
+            Token {
+                lemma: Owned("क्ष".to_string()),
+                char_end: 4,
+                byte_end: 12,
+                char_map: Some(vec![(3, 3), (3, 3), (3, 0), (3, 3)]),
+                script: Script::Devanagari,
+                ..Default::default()
+            },
+        ]
+    }
+
+    // expected result of the complete Normalizer pieline.
+    fn normalized_tokens() -> Vec<Token<'static>> {
+        vec![
+            Token {
+                lemma: Owned("कलम".to_string()),
+                char_end: 3,
+                byte_end: 9,
+                char_map: Some(vec![(3, 3), (3, 3), (3, 3)]),
+                script: Script::Devanagari,
+                kind: TokenKind::Word,
+                ..Default::default()
+            },
+            Token {
+                lemma: Owned("फोन".to_string()),
+                char_end: 4,
+                byte_end: 12,
+                char_map: Some(vec![(3, 3), (3, 0), (3, 3), (3, 3)]),
+                script: Script::Devanagari,
+                kind: TokenKind::Word,
+                ..Default::default()
+            },
+            Token {
+                lemma: Owned("हूं".to_string()),
+                char_end: 3,
+                byte_end: 9,
+                char_map: Some(vec![(3, 3), (3, 3), (3, 3)]),
+                script: Script::Devanagari,
+                kind: TokenKind::Word,
+                ..Default::default()
+            },
+            Token {
+                lemma: Owned("क्ष".to_string()),
+                char_end: 4,
+                byte_end: 12,
+                char_map: Some(vec![(3, 3), (3, 3), (3, 0), (3, 3)]),
+                script: Script::Devanagari,
+                kind: TokenKind::Word,
+                ..Default::default()
+            },
+        ]
+    }
+
+    test_normalizer!(DevanagariNormalizer, tokens(), normalizer_result(), normalized_tokens());
+}
@@ -0,0 +1,445 @@
+use super::{CharNormalizer, CharOrStr, Normalizer, NormalizerOption};
+use crate::detection::{Language, Script};
+use crate::Token;
+
+/// Decomposition of the Hangul syllables applied by the [`KoreanJamoNormalizer`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KoreanJamoMode {
+    /// Decomposes the syllables into conjoining jamo, splitting the compound vowels and consonants
+    /// and replacing the final consonants by the equivalent initial consonants,
+    /// so a partially typed word, like `학` while typing `하고`, is a prefix of the complete word.
+    Jamo,
+    /// Only keeps the initial consonant (choseong) of each syllable, like `ᄒᄀ` for `한국`.
+    Initials,
+}
+
+/// Korean specialized [`Normalizer`] decomposing the Hangul syllables into jamo.
+///
+/// The decomposition allows to match a word while its last syllable is being typed,
+/// this normalizer isn't part of the default [`NORMALIZERS`](super::NORMALIZERS),
+/// see [`TokenizerBuilder::korean_jamo`](crate::TokenizerBuilder::korean_jamo).
+#[derive(Debug, Clone, Copy)]
+pub struct KoreanJamoNormalizer {
+    mode: KoreanJamoMode,
+}
+
+impl KoreanJamoNormalizer {
+    /// Creates a normalizer decomposing the Hangul syllables with the given [`KoreanJamoMode`].
+    pub fn new(mode: KoreanJamoMode) -> Self {
+        Self { mode }
+    }
+}
+
+impl Normalizer for KoreanJamoNormalizer {
+    fn normalize<'o>(&self, token: Token<'o>, options: &NormalizerOption) -> Token<'o> {
+        match self.mode {
+            KoreanJamoMode::Jamo => KoreanJamoCharNormalizer::<false>.normalize(token, options),
+            KoreanJamoMode::Initials => KoreanJamoCharNormalizer::<true>.normalize(token, options),
+        }
+    }
+
+    fn should_normalize(&self, token: &Token) -> bool {
+        CharNormalizer::should_normalize(&KoreanJamoCharNormalizer::<false>, token)
+    }
+}
+
+/// Character normalizer of the [`KoreanJamoNormalizer`], only keeping the initial consonants if `INITIALS`.
+struct KoreanJamoCharNormalizer<const INITIALS: bool>;
+
+impl<const INITIALS: bool> CharNormalizer for KoreanJamoCharNormalizer<INITIALS> {
+    fn normalize_char(&self, c: char) -> Option<CharOrStr> {
+        let mut normalized = String::new();
+        for jamo in decompose_syllable(c).into_iter().flatten() {
+            if INITIALS {
+                if !is_medial_or_final(jamo) {
+                    normalized.push(jamo);
+                }
+            } else {
+                match split_jamo(jamo) {
+                    Some(split) => normalized.push_str(split),
+                    None => normalized.push(jamo),
+                }
+            }
+        }
+
+        let mut chars = normalized.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Some(c.into()),
+            (Some(_), Some(_)) => Some(normalized.into()),
+            (None, _) => None,
+        }
+    }
+
+    fn should_normalize(&self, token: &Token) -> bool {
+        token.script == Script::Hangul && matches!(token.language, None | Some(Language::Kor))
+    }
+}
+
+const SYLLABLE_BASE: u32 = 0xAC00;
+const INITIAL_BASE: u32 = 0x1100;
+const MEDIAL_BASE: u32 = 0x1161;
+const FINAL_BASE: u32 = 0x11A7;
+const MEDIAL_COUNT: u32 = 21;
+const FINAL_COUNT: u32 = 28;
+const SYLLABLE_COUNT: u32 = 11172;
+
+/// Decomposes a precomposed Hangul syllable into its conjoining jamo,
+/// any other character is returned as is.
+///
+/// In the tokenizer pipeline, the syllables are already decomposed by the `CompatibilityDecompositionNormalizer`,
+/// this decomposition only serves the callers normalizing the tokens with the [`KoreanJamoNormalizer`] alone.
+fn decompose_syllable(c: char) -> [Option<char>; 3] {
+    let index = (c as u32).wrapping_sub(SYLLABLE_BASE);
+    if index >= SYLLABLE_COUNT {
+        return [Some(c), None, None];
+    }
+
+    let initial = INITIAL_BASE + index / (MEDIAL_COUNT * FINAL_COUNT);
+    let medial = MEDIAL_BASE + (index % (MEDIAL_COUNT * FINAL_COUNT)) / FINAL_COUNT;
+    let final_index = index % FINAL_COUNT;
+    let final_ = (final_index != 0).then(|| FINAL_BASE + final_index);
+
+    [char::from_u32(initial), char::from_u32(medial), final_.and_then(char::from_u32)]
+}
+
+/// Returns true if the conjoining jamo is a vowel or a final consonant,
+/// including the archaic jamo and the jamo of the Hangul Jamo Extended-B block.
+fn is_medial_or_final(c: char) -> bool {
+    matches!(c, '\u{1160}'..='\u{11FF}' | '\u{D7B0}'..='\u{D7FF}')
+}
+
+/// Splits a compound vowel into its simple vowels
+/// and converts a final consonant into the equivalent initial consonants.
+///
+/// The archaic and extended jamo are split by the components of their Unicode names,
+/// like `ᅶ` (A-O) into `ᅡᅩ` and `ퟋ` (NIEUN-RIEUL) into `ᄂᄅ`, the single archaic vowels are kept as is.
+fn split_jamo(c: char) -> Option<&'static str> {
+    let split = match c {
+        // compound vowels.
+        'ᅪ' => "ᅩᅡ",
+        'ᅫ' => "ᅩᅢ",
+        'ᅬ' => "ᅩᅵ",
+        'ᅯ' => "ᅮᅥ",
+        'ᅰ' => "ᅮᅦ",
+        'ᅱ' => "ᅮᅵ",
+        'ᅴ' => "ᅳᅵ",
+        // final consonants.
+        'ᆨ' => "ᄀ",
+        'ᆩ' => "ᄁ",
+        'ᆪ' => "ᄀᄉ",
+        'ᆫ' => "ᄂ",
+        'ᆬ' => "ᄂᄌ",
+        'ᆭ' => "ᄂᄒ",
+        'ᆮ' => "ᄃ",
+        'ᆯ' => "ᄅ",
+        'ᆰ' => "ᄅᄀ",
+        'ᆱ' => "ᄅᄆ",
+        'ᆲ' => "ᄅᄇ",
+        'ᆳ' => "ᄅᄉ",
+        'ᆴ' => "ᄅᄐ",
+        'ᆵ' => "ᄅᄑ",
+        'ᆶ' => "ᄅᄒ",
+        'ᆷ' => "ᄆ",
+        'ᆸ' => "ᄇ",
+        'ᆹ' => "ᄇᄉ",
+        'ᆺ' => "ᄉ",
+        'ᆻ' => "ᄊ",
+        'ᆼ' => "ᄋ",
+        'ᆽ' => "ᄌ",
+        'ᆾ' => "ᄎ",
+        'ᆿ' => "ᄏ",
+        'ᇀ' => "ᄐ",
+        'ᇁ' => "ᄑ",
+        'ᇂ' => "ᄒ",
+        // archaic and extended compound vowels.
+        'ᅶ' => "ᅡᅩ",
+        'ᅷ' => "ᅡᅮ",
+        'ᅸ' => "ᅣᅩ",
+        'ᅹ' => "ᅣᅭ",
+        'ᅺ' => "ᅥᅩ",
+        'ᅻ' => "ᅥᅮ",
+        'ᅼ' => "ᅥᅳ",
+        'ᅽ' => "ᅧᅩ",
+        'ᅾ' => "ᅧᅮ",
+        'ᅿ' => "ᅩᅥ",
+        'ᆀ' => "ᅩᅦ",
+        'ᆁ' => "ᅩᅨ",
+        'ᆂ' => "ᅩᅩ",
+        'ᆃ' => "ᅩᅮ",
+        'ᆄ' => "ᅭᅣ",
+        'ᆅ' => "ᅭᅤ",
+        'ᆆ' => "ᅭᅧ",
+        'ᆇ' => "ᅭᅩ",
+        'ᆈ' => "ᅭᅵ",
+        'ᆉ' => "ᅮᅡ",
+        'ᆊ' => "ᅮᅢ",
+        'ᆋ' => "ᅮᅥᅳ",
+        'ᆌ' => "ᅮᅨ",
+        'ᆍ' => "ᅮᅮ",
+        'ᆎ' => "ᅲᅡ",
+        'ᆏ' => "ᅲᅥ",
+        'ᆐ' => "ᅲᅦ",
+        'ᆑ' => "ᅲᅧ",
+        'ᆒ' => "ᅲᅨ",
+        'ᆓ' => "ᅲᅮ",
+        'ᆔ' => "ᅲᅵ",
+        'ᆕ' => "ᅳᅮ",
+        'ᆖ' => "ᅳᅳ",
+        'ᆗ' => "ᅳᅵᅮ",
+        'ᆘ' => "ᅵᅡ",
+        'ᆙ' => "ᅵᅣ",
+        'ᆚ' => "ᅵᅩ",
+        'ᆛ' => "ᅵᅮ",
+        'ᆜ' => "ᅵᅳ",
+        'ᆝ' => "ᅵᆞ",
+        'ᆟ' => "ᆞᅥ",
+        'ᆠ' => "ᆞᅮ",
+        'ᆡ' => "ᆞᅵ",
+        'ᆣ' => "ᅡᅳ",
+        'ᆤ' => "ᅣᅮ",
+        'ᆥ' => "ᅧᅣ",
+        'ᆦ' => "ᅩᅣ",
+        'ᆧ' => "ᅩᅤ",
+        'ힰ' => "ᅩᅧ",
+        'ힱ' => "ᅩᅩᅵ",
+        'ힲ' => "ᅭᅡ",
+        'ힳ' => "ᅭᅢ",
+        'ힴ' => "ᅭᅥ",
+        'ힵ' => "ᅮᅧ",
+        'ힶ' => "ᅮᅵᅵ",
+        'ힷ' => "ᅲᅢ",
+        'ힸ' => "ᅲᅩ",
+        'ힹ' => "ᅳᅡ",
+        'ힺ' => "ᅳᅥ",
+        'ힻ' => "ᅳᅦ",
+        'ힼ' => "ᅳᅩ",
+        'ힽ' => "ᅵᅣᅩ",
+        'ힾ' => "ᅵᅤ",
+        'ힿ' => "ᅵᅧ",
+        'ퟀ' => "ᅵᅨ",
+        'ퟁ' => "ᅵᅩᅵ",
+        'ퟂ' => "ᅵᅭ",
+        'ퟃ' => "ᅵᅲ",
+        'ퟄ' => "ᅵᅵ",
+        'ퟅ' => "ᆞᅡ",
+        'ퟆ' => "ᆞᅦ",
+        // archaic and extended final consonants.
+        'ᇃ' => "ᄀᄅ",
+        'ᇄ' => "ᄀᄉᄀ",
+        'ᇅ' => "ᄂᄀ",
+        'ᇆ' => "ᄂᄃ",
+        'ᇇ' => "ᄂᄉ",
+        'ᇈ' => "ᄂᅀ",
+        'ᇉ' => "ᄂᄐ",
+        'ᇊ' => "ᄃᄀ",
+        'ᇋ' => "ᄃᄅ",
+        'ᇌ' => "ᄅᄀᄉ",
+        'ᇍ' => "ᄅᄂ",
+        'ᇎ' => "ᄅᄃ",
+        'ᇏ' => "ᄅᄃᄒ",
+        'ᇐ' => "ᄙ",
+        'ᇑ' => "ᄅᄆᄀ",
+        'ᇒ' => "ᄅᄆᄉ",
+        'ᇓ' => "ᄅᄇᄉ",
+        'ᇔ' => "ᄅᄇᄒ",
+        'ᇕ' => "ᄅᄫ",
+        'ᇖ' => "ᄅᄊ",
+        'ᇗ' => "ᄅᅀ",
+        'ᇘ' => "ᄅᄏ",
+        'ᇙ' => "ᄅᅙ",
+        'ᇚ' => "ᄆᄀ",
+        'ᇛ' => "ᄆᄅ",
+        'ᇜ' => "ᄆᄇ",
+        'ᇝ' => "ᄆᄉ",
+        'ᇞ' => "ᄆᄊ",
+        'ᇟ' => "ᄆᅀ",
+        'ᇠ' => "ᄆᄎ",
+        'ᇡ' => "ᄆᄒ",
+        'ᇢ' => "ᄝ",
+        'ᇣ' => "ᄇᄅ",
+        'ᇤ' => "ᄇᄑ",
+        'ᇥ' => "ᄇᄒ",
+        'ᇦ' => "ᄫ",
+        'ᇧ' => "ᄉᄀ",
+        'ᇨ' => "ᄉᄃ",
+        'ᇩ' => "ᄉᄅ",
+        'ᇪ' => "ᄉᄇ",
+        'ᇫ' => "ᅀ",
+        'ᇬ' => "ᄋᄀ",
+        'ᇭ' => "ᄋᄁ",
+        'ᇮ' => "ᅇ",
+        'ᇯ' => "ᄋᄏ",
+        'ᇰ' => "ᅌ",
+        'ᇱ' => "ᅌᄉ",
+        'ᇲ' => "ᅌᅀ",
+        'ᇳ' => "ᄑᄇ",
+        'ᇴ' => "ᅗ",
+        'ᇵ' => "ᄒᄂ",
+        'ᇶ' => "ᄒᄅ",
+        'ᇷ' => "ᄒᄆ",
+        'ᇸ' => "ᄒᄇ",
+        'ᇹ' => "ᅙ",
+        'ᇺ' => "ᄀᄂ",
+        'ᇻ' => "ᄀᄇ",
+        'ᇼ' => "ᄀᄎ",
+        'ᇽ' => "ᄀᄏ",
+        'ᇾ' => "ᄀᄒ",
+        'ᇿ' => "ᄔ",
+        'ퟋ' => "ᄂᄅ",
+        'ퟌ' => "ᄂᄎ",
+        'ퟍ' => "ᄄ",
+        'ퟎ' => "ᄄᄇ",
+        'ퟏ' => "ᄃᄇ",
+        'ퟐ' => "ᄃᄉ",
+        'ퟑ' => "ᄃᄉᄀ",
+        'ퟒ' => "ᄃᄌ",
+        'ퟓ' => "ᄃᄎ",
+        'ퟔ' => "ᄃᄐ",
+        'ퟕ' => "ᄅᄁ",
+        'ퟖ' => "ᄅᄀᄒ",
+        'ퟗ' => "ᄙᄏ",
+        'ퟘ' => "ᄅᄆᄒ",
+        'ퟙ' => "ᄅᄇᄃ",
+        'ퟚ' => "ᄅᄇᄑ",
+        'ퟛ' => "ᄅᅌ",
+        'ퟜ' => "ᄅᅙᄒ",
+        'ퟝ' => "ᄛ",
+        'ퟞ' => "ᄆᄂ",
+        'ퟟ' => "ᄆᄔ",
+        'ퟠ' => "ᄆᄆ",
+        'ퟡ' => "ᄆᄇᄉ",
+        'ퟢ' => "ᄆᄌ",
+        'ퟣ' => "ᄇᄃ",
+        'ퟤ' => "ᄇᄅᄑ",
+        'ퟥ' => "ᄇᄆ",
+        'ퟦ' => "ᄈ",
+        'ퟧ' => "ᄇᄉᄃ",
+        'ퟨ' => "ᄇᄌ",
+        'ퟩ' => "ᄇᄎ",
+        'ퟪ' => "ᄉᄆ",
+        'ퟫ' => "ᄉᄫ",
+        'ퟬ' => "ᄊᄀ",
+        'ퟭ' => "ᄊᄃ",
+        'ퟮ' => "ᄉᅀ",
+        'ퟯ' => "ᄉᄌ",
+        'ퟰ' => "ᄉᄎ",
+        'ퟱ' => "ᄉᄐ",
+        'ퟲ' => "ᄉᄒ",
+        'ퟳ' => "ᅀᄇ",
+        'ퟴ' => "ᅀᄫ",
+        'ퟵ' => "ᅌᄆ",
+        'ퟶ' => "ᅌᄒ",
+        'ퟷ' => "ᄌᄇ",
+        'ퟸ' => "ᄌᄈ",
+        'ퟹ' => "ᄍ",
+        'ퟺ' => "ᄑᄉ",
+        'ퟻ' => "ᄑᄐ",
+        _ => return None,
+    };
+
+    Some(split)
+}
+
+#[cfg(test)]
+mod test {
+    use std::borrow::Cow;
+
+    use super::*;
+    use crate::normalizer::{NonspacingMarkNormalizer, NormalizerPipeline, NORMALIZERS};
+    use crate::{Normalize, Script};
+
+    fn options() -> NormalizerOption<'static> {
+        NormalizerOption { create_char_map: true, ..NormalizerOption::new() }
+    }
+
+    fn pipeline_options(mode: KoreanJamoMode) -> NormalizerOption<'static> {
+        let mut normalizers = NormalizerPipeline::new();
+        normalizers.insert_before::<NonspacingMarkNormalizer, _>(KoreanJamoNormalizer::new(mode));
+        NormalizerOption { normalizers: Some(Cow::Owned(normalizers)), ..options() }
+    }
+
+    fn hangul_token(lemma: &str) -> Token<'_> {
+        Token {
+            lemma: Cow::Borrowed(lemma),
+            char_end: lemma.chars().count(),
+            byte_end: lemma.len(),
+            script: Script::Hangul,
+            language: Some(Language::Kor),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn decompose_syllables() {
+        assert_eq!(decompose_syllable('한'), [Some('ᄒ'), Some('ᅡ'), Some('ᆫ')]);
+        assert_eq!(decompose_syllable('가'), [Some('ᄀ'), Some('ᅡ'), None]);
+        assert_eq!(decompose_syllable('힣'), [Some('ᄒ'), Some('ᅵ'), Some('ᇂ')]);
+        assert_eq!(decompose_syllable('a'), [Some('a'), None, None]);
+    }
+
+    #[test]
+    fn jamo() {
+        let normalizer = KoreanJamoNormalizer::new(KoreanJamoMode::Jamo);
+        let token = normalizer.normalize(hangul_token("한국"), &options());
+        assert_eq!(token.lemma(), "\u{1112}\u{1161}\u{1102}\u{1100}\u{116E}\u{1100}");
+        assert_eq!(token.char_map, Some(vec![(3, 9), (3, 9)]));
+
+        // compound vowels and consonants are split.
+        let token = normalizer.normalize(hangul_token("닭과"), &options());
+        assert_eq!(token.lemma(), "\u{1103}\u{1161}\u{1105}\u{1100}\u{1100}\u{1169}\u{1161}");
+        assert_eq!(token.char_map, Some(vec![(3, 12), (3, 9)]));
+    }
+
+    #[test]
+    fn initials() {
+        let normalizer = KoreanJamoNormalizer::new(KoreanJamoMode::Initials);
+        let token = normalizer.normalize(hangul_token("한국"), &options());
+        assert_eq!(token.lemma(), "\u{1112}\u{1100}");
+        assert_eq!(token.char_map, Some(vec![(3, 3), (3, 3)]));
+    }
+
+    #[test]
+    fn archaic_and_extended_jamo() {
+        // an archaic vowel (ARAEA) and final consonant (PANSIOS).
+        let archaic = "\u{1100}\u{119E}\u{11EB}";
+        // an extended compound vowel (O-YEO) and final consonant (NIEUN-RIEUL).
+        let extended = "\u{1100}\u{D7B0}\u{D7CB}";
+
+        let normalizer = KoreanJamoNormalizer::new(KoreanJamoMode::Jamo);
+        let token = normalizer.normalize(hangul_token(archaic), &options());
+        assert_eq!(token.lemma(), "\u{1100}\u{119E}\u{1140}");
+        let token = normalizer.normalize(hangul_token(extended), &options());
+        assert_eq!(token.lemma(), "\u{1100}\u{1169}\u{1167}\u{1102}\u{1105}");
+        assert_eq!(token.char_map, Some(vec![(3, 3), (3, 6), (3, 6)]));
+
+        let normalizer = KoreanJamoNormalizer::new(KoreanJamoMode::Initials);
+        let token = normalizer.normalize(hangul_token(archaic), &options());
+        assert_eq!(token.lemma(), "\u{1100}");
+        let token = normalizer.normalize(hangul_token(extended), &options());
+        assert_eq!(token.lemma(), "\u{1100}");
+    }
+
+    #[test]
+    fn disabled_by_default() {
+        assert!(!NORMALIZERS.contains::<KoreanJamoNormalizer>());
+    }
+
+    #[test]
+    fn partial_syllables() {
+        let normalize =
+            |text, mode| hangul_token(text).normalize(&pipeline_options(mode)).lemma.into_owned();
+
+        // the syllables are first decomposed by the `CompatibilityDecompositionNormalizer`.
+        let word = normalize("하고", KoreanJamoMode::Jamo);
+        assert!(word.starts_with(&normalize("학", KoreanJamoMode::Jamo)));
+        let word = normalize("한국", KoreanJamoMode::Jamo);
+        assert!(word.starts_with(&normalize("한ㄱ", KoreanJamoMode::Jamo)));
+        let word = normalize("과자", KoreanJamoMode::Jamo);
+        assert!(word.starts_with(&normalize("고", KoreanJamoMode::Jamo)));
+
+        assert_eq!(
+            normalize("한국", KoreanJamoMode::Initials),
+            normalize("ㅎㄱ", KoreanJamoMode::Initials)
+        );
+    }
+}
@@ -0,0 +1,271 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+
+use fst::{Map, MapBuilder};
+use unicode_normalization::{is_nfc, UnicodeNormalization};
+
+use super::{replace_lemma, Normalizer, NormalizerOption};
+use crate::{Language, Token};
+
+/// A [`Normalizer`] replacing inflected words by their dictionary form.
+///
+/// This Normalizer looks up each word in the [`Lexicon`] given for the [`Language`] of the token,
+/// this allows handling irregular forms that a stemmer can't, for instance `went` becomes `go` and `mice` becomes `mouse`.
+/// Tokens without a detected `Language`, without a `Lexicon` for their `Language`
+/// or that are not in the `Lexicon` are not changed.
+///
+/// Lexicons are provided using [`LemmatizerNormalizer::with_lexicon`] or [`TokenizerBuilder::lexicon`],
+/// this normalizer isn't part of the default [`NORMALIZERS`](super::NORMALIZERS),
+/// a tokenizer using it detects the `Language` of every token, an allow list restricts the detected languages.
+///
+/// [`TokenizerBuilder::lexicon`]: crate::TokenizerBuilder::lexicon
+#[derive(Debug, Clone, Default)]
+pub struct LemmatizerNormalizer {
+    lexicons: HashMap<Language, Arc<Lexicon>>,
+}
+
+impl LemmatizerNormalizer {
+    /// Creates a normalizer without any [`Lexicon`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the [`Lexicon`] used to lemmatize the tokens of the given [`Language`],
+    /// replacing the previous `Lexicon` of this `Language`.
+    pub fn with_lexicon(mut self, language: Language, lexicon: impl Into<Arc<Lexicon>>) -> Self {
+        self.lexicons.insert(language, lexicon.into());
+        self
+    }
+
+    fn lexicon(&self, token: &Token) -> Option<&Lexicon> {
+        self.lexicons.get(&token.language?).map(Arc::as_ref)
+    }
+}
+
+impl Normalizer for LemmatizerNormalizer {
+    fn normalize<'o>(&self, mut token: Token<'o>, options: &NormalizerOption) -> Token<'o> {
+        let Some(lexicon) = self.lexicon(&token) else {
+            return token;
+        };
+
+        // lexicons contain composed characters,
+        // so the lemma is recomposed before the lookup and the result is decomposed back.
+        let lemma = token.lemma.as_ref();
+        let new_lemma = if is_nfc(lemma) {
+            lexicon.lemma(lemma).map(str::to_string)
+        } else {
+            let composed: String = lemma.nfc().collect();
+            lexicon.lemma(&composed).map(|lemma| lemma.nfd().collect())
+        };
+
+        if let Some(new_lemma) = new_lemma.filter(|new_lemma| new_lemma != lemma) {
+            replace_lemma(&mut token, new_lemma, options);
+        }
+
+        token
+    }
+
+    fn should_normalize(&self, token: &Token) -> bool {
+        token.is_word() && token.language.is_some()
+    }
+}
+
+/// Dictionary mapping surface forms to their lemma, used by the [`LemmatizerNormalizer`].
+///
+/// The forms are stored in an [`fst::Map`] whose values are the indexes of the lemmas in a list,
+/// the forms should be lowercased and in Unicode composed form (NFC) to match the normalized tokens.
+/// The `fst::Map` can be built at runtime or loaded from any bytes, like a map included with `include_bytes!`.
+///
+/// # Example
+///
+/// ```
+/// use charabia::normalizer::Lexicon;
+///
+/// let lexicon = Lexicon::from_pairs([("went", "go"), ("gone", "go"), ("mice", "mouse")]).unwrap();
+///
+/// assert_eq!(lexicon.lemma("went"), Some("go"));
+/// assert_eq!(lexicon.lemma("mice"), Some("mouse"));
+/// assert_eq!(lexicon.lemma("cats"), None);
+/// ```
+pub struct Lexicon {
+    forms: Map<LexiconBytes>,
+    lemmas: Vec<String>,
+}
+
+/// Bytes of the [`fst::Map`] of a [`Lexicon`], owned or borrowed.
+struct LexiconBytes(Box<dyn AsRef<[u8]> + Send + Sync>);
+
+impl AsRef<[u8]> for LexiconBytes {
+    fn as_ref(&self) -> &[u8] {
+        self.0.as_ref().as_ref()
+    }
+}
+
+impl Lexicon {
+    /// Creates a lexicon from an [`fst::Map`] associating each form to the index of its lemma in `lemmas`.
+    pub fn new<D>(forms: Map<D>, lemmas: Vec<String>) -> Result<Self, fst::Error>
+    where
+        D: AsRef<[u8]> + Send + Sync + 'static,
+    {
+        let forms = forms.map_data(|bytes| LexiconBytes(Box::new(bytes)))?;
+        Ok(Self { forms, lemmas })
+    }
+
+    /// Creates a lexicon from a list of `(form, lemma)` pairs.
+    ///
+    /// If a form is present several times, the first lemma is kept.
+    pub fn from_pairs<I, F, L>(pairs: I) -> Result<Self, fst::Error>
+    where
+        I: IntoIterator<Item = (F, L)>,
+        F: AsRef<str>,
+        L: AsRef<str>,
+    {
+        let mut lemmas: Vec<String> = Vec::new();
+        let mut indexes: HashMap<String, u64> = HashMap::new();
+        let mut forms: Vec<(String, u64)> = Vec::new();
+        for (form, lemma) in pairs {
+            let lemma = lemma.as_ref();
+            let index = match indexes.get(lemma) {
+                Some(index) => *index,
+                None => {
+                    let index = lemmas.len() as u64;
+                    lemmas.push(lemma.to_string());
+                    indexes.insert(lemma.to_string(), index);
+                    index
+                }
+            };
+            forms.push((form.as_ref().to_string(), index));
+        }
+
+        // fst::Map requires sorted and unique keys.
+        forms.sort_by(|(a, _), (b, _)| a.cmp(b));
+        forms.dedup_by(|(a, _), (b, _)| a == b);
+
+        let mut builder = MapBuilder::memory();
+        builder.extend_iter(forms)?;
+
+        Self::new(builder.into_map(), lemmas)
+    }
+
+    /// Returns the lemma of the provided form if the form is in the lexicon.
+    pub fn lemma(&self, form: &str) -> Option<&str> {
+        let index = self.forms.get(form)?;
+        self.lemmas.get(index as usize).map(String::as_str)
+    }
+}
+
+impl fmt::Debug for Lexicon {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Lexicon")
+            .field("forms", &self.forms.len())
+            .field("lemmas", &self.lemmas.len())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::borrow::Cow::{self, Owned};
+
+    use fst::{Map, MapBuilder};
+
+    use super::{LemmatizerNormalizer, Lexicon};
+    use crate::normalizer::{
+        NonspacingMarkNormalizer, Normalizer, NormalizerOption, NormalizerPipeline, NORMALIZERS,
+    };
+    use crate::token::TokenKind;
+    use crate::{Language, Normalize, Script, Token};
+
+    fn lemmatizer() -> LemmatizerNormalizer {
+        let english = Lexicon::from_pairs([("went", "go"), ("mice", "mouse")]).unwrap();
+        let german = Lexicon::from_pairs([("ging", "gehen"), ("häuser", "haus")]).unwrap();
+        LemmatizerNormalizer::new()
+            .with_lexicon(Language::Eng, english)
+            .with_lexicon(Language::Deu, german)
+    }
+
+    fn options() -> NormalizerOption<'static> {
+        NormalizerOption { create_char_map: true, ..NormalizerOption::new() }
+    }
+
+    fn token(lemma: &str, language: Option<Language>) -> Token<'static> {
+        Token {
+            lemma: Owned(lemma.to_string()),
+            char_end: lemma.chars().count(),
+            byte_end: lemma.len(),
+            kind: TokenKind::Word,
+            script: Script::Latin,
+            language,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn lemmatize() {
+        let lemmatizer = lemmatizer();
+        let options = options();
+        let tokens = [
+            token("went", Some(Language::Eng)),
+            token("mice", Some(Language::Eng)),
+            token("ging", Some(Language::Deu)),
+            token("cats", Some(Language::Eng)),
+            token("went", Some(Language::Fra)),
+            token("went", None),
+        ];
+
+        let lemmas: Vec<_> = tokens
+            .into_iter()
+            .map(|token| {
+                if lemmatizer.should_normalize(&token) {
+                    lemmatizer.normalize(token, &options)
+                } else {
+                    token
+                }
+            })
+            .map(|token| token.lemma.into_owned())
+            .collect();
+
+        assert_eq!(lemmas, ["go", "mouse", "gehen", "cats", "went", "went"]);
+    }
+
+    #[test]
+    fn lemmatize_char_map() {
+        let token = lemmatizer().normalize(token("mice", Some(Language::Eng)), &options());
+
+        assert_eq!(token.lemma(), "mouse");
+        assert_eq!(token.char_map, Some(vec![(1, 1), (1, 4), (1, 0), (1, 0)]));
+    }
+
+    #[test]
+    fn global_normalize() {
+        let mut normalizers = NormalizerPipeline::new();
+        normalizers.insert_before::<NonspacingMarkNormalizer, _>(lemmatizer());
+        let options = NormalizerOption { normalizers: Some(Cow::Owned(normalizers)), ..options() };
+        let tokens = [token("Häuser", Some(Language::Deu)), token("Went", Some(Language::Eng))];
+
+        let lemmas: Vec<_> =
+            tokens.into_iter().map(|token| token.normalize(&options).lemma.into_owned()).collect();
+
+        assert_eq!(lemmas, ["haus", "go"]);
+    }
+
+    #[test]
+    fn borrowed_map() {
+        let mut builder = MapBuilder::memory();
+        builder.extend_iter([("mice", 0), ("went", 1)]).unwrap();
+        // like a map included with `include_bytes!`.
+        let bytes: &'static [u8] = builder.into_inner().unwrap().leak();
+
+        let lexicon =
+            Lexicon::new(Map::new(bytes).unwrap(), vec!["mouse".into(), "go".into()]).unwrap();
+        assert_eq!(lexicon.lemma("went"), Some("go"));
+        assert_eq!(lexicon.lemma("mice"), Some("mouse"));
+        assert_eq!(lexicon.lemma("cats"), None);
+    }
+
+    #[test]
+    fn disabled_by_default() {
+        assert!(!NORMALIZERS.contains::<LemmatizerNormalizer>());
+    }
+}
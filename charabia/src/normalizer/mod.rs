@@ -1,23 +1,34 @@
+use std::any::{type_name, TypeId};
 use std::borrow::Cow;
-use std::sync::LazyLock;
+use std::fmt;
+use std::sync::{Arc, LazyLock};
 
 pub use self::ae_oe_normalizer::AeOeNormalizer;
 pub use self::arabic::ArabicNormalizer;
 #[cfg(feature = "chinese-normalization")]
 pub use self::chinese::ChineseNormalizer;
-pub use self::classify::{Classifier, ClassifierOption};
+pub(crate) use self::classify::CONTEXT_SEPARATOR_SET;
+pub use self::classify::{
+    Classifier, ClassifierOption, JAPANESE_STOP_PARTS_OF_SPEECH, KOREAN_STOP_PARTS_OF_SPEECH,
+};
 pub use self::compatibility_decomposition::CompatibilityDecompositionNormalizer;
 pub use self::control_char::ControlCharNormalizer;
+#[cfg(feature = "devanagari")]
+pub use self::devanagari::DevanagariNormalizer;
 #[cfg(feature = "greek")]
-use self::greek::GreekNormalizer;
+pub use self::greek::GreekNormalizer;
 #[cfg(feature = "japanese-transliteration")]
 pub use self::japanese::JapaneseNormalizer;
+pub use self::korean::{KoreanJamoMode, KoreanJamoNormalizer};
+pub use self::lemmatizer::{LemmatizerNormalizer, Lexicon};
 pub use self::lowercase::LowercaseNormalizer;
-use self::nonspacing_mark::NonspacingMarkNormalizer;
+pub use self::nonspacing_mark::NonspacingMarkNormalizer;
 pub use self::persian::PersianNormalizer;
-use self::quote::QuoteNormalizer;
+pub use self::quote::QuoteNormalizer;
+#[cfg(feature = "stemming")]
+pub use self::stemming::StemmingNormalizer;
 #[cfg(feature = "swedish-recomposition")]
-use self::swedish_recomposition::SwedishRecompositionNormalizer;
+pub use self::swedish_recomposition::SwedishRecompositionNormalizer;
 #[cfg(feature = "turkish")]
 pub use self::turkish::TurkishNormalizer;
 #[cfg(feature = "vietnamese")]
@@ -31,13 +42,19 @@ mod chinese;
 mod classify;
 mod compatibility_decomposition;
 mod control_char;
+#[cfg(feature = "devanagari")]
+mod devanagari;
 #[cfg(feature = "greek")]
 mod greek;
 #[cfg(feature = "japanese-transliteration")]
 mod japanese;
+mod korean;
+mod lemmatizer;
 mod lowercase;
 mod nonspacing_mark;
 mod quote;
+#[cfg(feature = "stemming")]
+mod stemming;
 #[cfg(feature = "swedish-recomposition")]
 mod swedish_recomposition;
 #[cfg(feature = "turkish")]
@@ -48,44 +65,241 @@ mod vietnamese;
 mod ae_oe_normalizer;
 mod persian;
 
-/// List of [`Normalizer`]s used by [`Normalize::normalize`] that are not considered lossy.
-pub static NORMALIZERS: LazyLock<Vec<Box<dyn Normalizer>>> = LazyLock::new(|| {
-    vec![
-        Box::new(CompatibilityDecompositionNormalizer),
-        #[cfg(feature = "swedish-recomposition")]
-        Box::new(SwedishRecompositionNormalizer),
-        Box::new(ControlCharNormalizer),
-        Box::new(Classifier),
-        Box::new(PersianNormalizer),
-    ]
+/// List of [`Normalizer`]s used by [`Normalize::normalize`].
+///
+/// The normalizers that are considered lossy are only applied if [`NormalizerOption::lossy`] is enabled.
+///
+/// This list is the one used by default, see [`NormalizerPipeline`] to use a custom list.
+pub static NORMALIZERS: LazyLock<NormalizerPipeline> = LazyLock::new(|| {
+    let mut pipeline = NormalizerPipeline::empty();
+
+    pipeline.push(CompatibilityDecompositionNormalizer);
+    #[cfg(feature = "swedish-recomposition")]
+    pipeline.push(SwedishRecompositionNormalizer);
+    pipeline.push(ControlCharNormalizer);
+    pipeline.push(Classifier);
+    pipeline.push(PersianNormalizer);
+
+    pipeline.push_lossy(LowercaseNormalizer);
+    pipeline.push_lossy(QuoteNormalizer);
+    pipeline.push_lossy(AeOeNormalizer);
+    #[cfg(feature = "chinese-normalization")]
+    pipeline.push_lossy(ChineseNormalizer::default());
+    #[cfg(feature = "japanese-transliteration")]
+    pipeline.push_lossy(JapaneseNormalizer);
+    #[cfg(feature = "greek")]
+    pipeline.push_lossy(GreekNormalizer);
+    pipeline.push_lossy(ArabicNormalizer);
+    #[cfg(feature = "devanagari")]
+    pipeline.push_lossy(DevanagariNormalizer);
+    pipeline.push_lossy(NonspacingMarkNormalizer);
+    #[cfg(feature = "vietnamese")]
+    pipeline.push_lossy(VietnameseNormalizer);
+    #[cfg(feature = "turkish")]
+    pipeline.push_lossy(TurkishNormalizer);
+
+    pipeline
 });
 
-/// List of [`Normalizer`]s used by [`Normalize::normalize`] that are considered lossy.
+/// List of the lossy [`Normalizer`]s of [`NORMALIZERS`].
+#[deprecated(
+    since = "0.10.0",
+    note = "the normalizers are now listed in a `NormalizerPipeline`, use `NORMALIZERS` instead"
+)]
 pub static LOSSY_NORMALIZERS: LazyLock<Vec<Box<dyn Normalizer>>> = LazyLock::new(|| {
-    vec![
-        Box::new(LowercaseNormalizer),
-        Box::new(QuoteNormalizer),
-        Box::new(AeOeNormalizer),
-        #[cfg(feature = "chinese-normalization")]
-        Box::new(ChineseNormalizer),
-        #[cfg(feature = "japanese-transliteration")]
-        Box::new(JapaneseNormalizer),
-        #[cfg(feature = "greek")]
-        Box::new(GreekNormalizer),
-        Box::new(ArabicNormalizer),
-        Box::new(NonspacingMarkNormalizer),
-        #[cfg(feature = "vietnamese")]
-        Box::new(VietnameseNormalizer),
-        #[cfg(feature = "turkish")]
-        Box::new(TurkishNormalizer),
-    ]
+    NORMALIZERS
+        .normalizers
+        .iter()
+        .filter(|entry| entry.lossy)
+        .map(|entry| Box::new(SharedNormalizer(entry.normalizer.clone())) as Box<dyn Normalizer>)
+        .collect()
 });
 
-pub(crate) const DEFAULT_NORMALIZER_OPTION: NormalizerOption = NormalizerOption {
-    create_char_map: false,
-    lossy: true,
-    classifier: ClassifierOption { stop_words: None, separators: None },
-};
+/// [`Normalizer`] shared with a [`NormalizerPipeline`].
+struct SharedNormalizer(Arc<dyn Normalizer>);
+
+impl Normalizer for SharedNormalizer {
+    fn normalize<'o>(&self, token: Token<'o>, options: &NormalizerOption) -> Token<'o> {
+        self.0.normalize(token, options)
+    }
+
+    fn should_normalize(&self, token: &Token) -> bool {
+        self.0.should_normalize(token)
+    }
+}
+
+/// Ordered list of [`Normalizer`]s applied on each [`Token`].
+///
+/// Normalizers are identified by their type,
+/// a pipeline can start from the default normalizers listed in [`NORMALIZERS`] and then be customized
+/// before being given to [`TokenizerBuilder::normalizers`].
+///
+/// [`TokenizerBuilder::normalizers`]: crate::TokenizerBuilder::normalizers
+///
+/// # Example
+///
+/// ```
+/// use charabia::normalizer::{Classifier, NonspacingMarkNormalizer, NormalizerPipeline};
+/// use charabia::TokenizerBuilder;
+///
+/// // start from the default normalizers and keep the diacritics.
+/// let mut normalizers = NormalizerPipeline::new();
+/// normalizers.remove::<NonspacingMarkNormalizer>();
+///
+/// let mut builder = TokenizerBuilder::default();
+/// builder.normalizers(&normalizers);
+/// let tokenizer = builder.build();
+///
+/// let output: Vec<_> = tokenizer.tokenize("Maïs").map(|t| t.lemma().to_string()).collect();
+/// assert_eq!(output, ["mai\u{308}s"]);
+/// ```
+#[derive(Clone)]
+pub struct NormalizerPipeline {
+    normalizers: Vec<PipelineEntry>,
+}
+
+#[derive(Clone)]
+struct PipelineEntry {
+    type_id: TypeId,
+    name: &'static str,
+    normalizer: Arc<dyn Normalizer>,
+    lossy: bool,
+}
+
+impl PipelineEntry {
+    fn new<N: Normalizer + 'static>(normalizer: N, lossy: bool) -> Self {
+        Self {
+            type_id: TypeId::of::<N>(),
+            name: type_name::<N>(),
+            normalizer: Arc::new(normalizer),
+            lossy,
+        }
+    }
+}
+
+impl NormalizerPipeline {
+    /// Creates a pipeline containing the default normalizers listed in [`NORMALIZERS`].
+    pub fn new() -> Self {
+        NORMALIZERS.clone()
+    }
+
+    /// Creates a pipeline without any normalizer.
+    ///
+    /// Note that the [`Classifier`] is a normalizer too,
+    /// without it every token stays classified as [`TokenKind::Unknown`](crate::TokenKind::Unknown).
+    pub fn empty() -> Self {
+        Self { normalizers: Vec::new() }
+    }
+
+    /// Appends a [`Normalizer`] at the end of the pipeline.
+    pub fn push<N: Normalizer + 'static>(&mut self, normalizer: N) -> &mut Self {
+        self.normalizers.push(PipelineEntry::new(normalizer, false));
+        self
+    }
+
+    /// Appends a lossy [`Normalizer`] at the end of the pipeline.
+    ///
+    /// A lossy normalizer is only applied if [`NormalizerOption::lossy`] is enabled.
+    pub fn push_lossy<N: Normalizer + 'static>(&mut self, normalizer: N) -> &mut Self {
+        self.normalizers.push(PipelineEntry::new(normalizer, true));
+        self
+    }
+
+    /// Inserts a [`Normalizer`] just before the normalizer of type `T`.
+    ///
+    /// The inserted normalizer is considered lossy if the normalizer of type `T` is.
+    /// Returns `false` if the pipeline doesn't contain any normalizer of type `T`.
+    pub fn insert_before<T: 'static, N: Normalizer + 'static>(&mut self, normalizer: N) -> bool {
+        match self.position::<T>() {
+            Some(index) => {
+                let lossy = self.normalizers[index].lossy;
+                self.normalizers.insert(index, PipelineEntry::new(normalizer, lossy));
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Inserts a [`Normalizer`] just after the normalizer of type `T`.
+    ///
+    /// The inserted normalizer is considered lossy if the normalizer of type `T` is.
+    /// Returns `false` if the pipeline doesn't contain any normalizer of type `T`.
+    pub fn insert_after<T: 'static, N: Normalizer + 'static>(&mut self, normalizer: N) -> bool {
+        match self.position::<T>() {
+            Some(index) => {
+                let lossy = self.normalizers[index].lossy;
+                self.normalizers.insert(index + 1, PipelineEntry::new(normalizer, lossy));
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Replaces the normalizer of type `T` by the given [`Normalizer`], keeping its position.
+    ///
+    /// The new normalizer is considered lossy if the replaced normalizer is.
+    /// Returns `false` if the pipeline doesn't contain any normalizer of type `T`.
+    pub fn replace<T: 'static, N: Normalizer + 'static>(&mut self, normalizer: N) -> bool {
+        match self.position::<T>() {
+            Some(index) => {
+                let lossy = self.normalizers[index].lossy;
+                self.normalizers[index] = PipelineEntry::new(normalizer, lossy);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Removes the normalizer of type `T` from the pipeline.
+    ///
+    /// Returns `false` if the pipeline doesn't contain any normalizer of type `T`.
+    pub fn remove<T: 'static>(&mut self) -> bool {
+        let len = self.normalizers.len();
+        self.normalizers.retain(|entry| entry.type_id != TypeId::of::<T>());
+        self.normalizers.len() != len
+    }
+
+    /// Returns true if the pipeline contains a normalizer of type `T`.
+    pub fn contains<T: 'static>(&self) -> bool {
+        self.position::<T>().is_some()
+    }
+
+    /// Returns the number of normalizers in the pipeline.
+    pub fn len(&self) -> usize {
+        self.normalizers.len()
+    }
+
+    /// Returns true if the pipeline doesn't contain any normalizer.
+    pub fn is_empty(&self) -> bool {
+        self.normalizers.is_empty()
+    }
+
+    fn position<T: 'static>(&self) -> Option<usize> {
+        self.normalizers.iter().position(|entry| entry.type_id == TypeId::of::<T>())
+    }
+
+    /// Returns an iterator over the normalizers to apply depending on the lossy option.
+    fn iter(&self, lossy: bool) -> impl Iterator<Item = &dyn Normalizer> {
+        self.normalizers
+            .iter()
+            .filter(move |entry| lossy || !entry.lossy)
+            .map(|entry| &*entry.normalizer)
+    }
+}
+
+impl Default for NormalizerPipeline {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for NormalizerPipeline {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.normalizers.iter().map(|entry| entry.name)).finish()
+    }
+}
+
+pub(crate) static DEFAULT_NORMALIZER_OPTION: NormalizerOption = NormalizerOption::new();
 
 /// Iterator over Normalized [`Token`]s.
 pub struct NormalizedTokenIter<'o, 'aho, 'lang, 'tb> {
@@ -102,11 +316,34 @@ impl<'o> Iterator for NormalizedTokenIter<'o, '_, '_, '_> {
 }
 
 /// Structure for providing options to a normalizer.
+///
+/// The options of a specific normalizer, like the [`Lexicon`]s of the [`LemmatizerNormalizer`],
+/// are given to the normalizer itself when it is added to a [`NormalizerPipeline`].
 #[derive(Debug, Clone, Default)]
+#[non_exhaustive]
 pub struct NormalizerOption<'tb> {
     pub create_char_map: bool,
     pub classifier: ClassifierOption<'tb>,
     pub lossy: bool,
+    /// Custom list of normalizers, if `None` the default [`NORMALIZERS`] are used.
+    pub normalizers: Option<Cow<'tb, NormalizerPipeline>>,
+}
+
+impl NormalizerOption<'_> {
+    /// Creates the options of the default normalization,
+    /// the lossy normalization is enabled and the `char_map` isn't created.
+    pub const fn new() -> Self {
+        Self {
+            create_char_map: false,
+            classifier: ClassifierOption::new(),
+            lossy: true,
+            normalizers: None,
+        }
+    }
+
+    pub(crate) fn normalizers(&self) -> &NormalizerPipeline {
+        self.normalizers.as_deref().unwrap_or(&NORMALIZERS)
+    }
 }
 
 /// Trait defining a normalizer.
@@ -131,6 +368,63 @@ fn shrink_cow<'o>(s: &Cow<'o, str>, new_size: usize) -> Cow<'o, str> {
     }
 }
 
+/// Replaces the lemma of the token by a word-level normalized version of it,
+/// keeping the `char_map` in sync with the new lemma.
+///
+/// The `char_map` is dropped if the new lemma can't be mapped on the original characters.
+fn replace_lemma(token: &mut Token, new_lemma: String, options: &NormalizerOption) {
+    let lemma = token.lemma.as_ref();
+    let char_map = match token.char_map.take() {
+        Some(char_map) => Some(char_map),
+        None if options.create_char_map => {
+            Some(lemma.chars().map(|c| (c.len_utf8() as u8, c.len_utf8() as u8)).collect())
+        }
+        None => None,
+    };
+    token.char_map = char_map.and_then(|mut char_map| {
+        realign_char_map(&mut char_map, lemma, &new_lemma).map(|()| char_map)
+    });
+
+    token.lemma = Cow::Owned(new_lemma);
+}
+
+/// Updates the normalized lengths of the `char_map` after the lemma changed from `old` to `new`.
+///
+/// The characters of the common prefix keep their normalized lengths,
+/// the remaining bytes of `new` are assigned to the first character following the prefix,
+/// and the other characters are mapped to nothing.
+///
+/// Returns `None` if a normalized length doesn't fit in a `u8`, the `char_map` is then inconsistent.
+fn realign_char_map(char_map: &mut [(u8, u8)], old: &str, new: &str) -> Option<()> {
+    let prefix_len = old
+        .char_indices()
+        .zip(new.chars())
+        .take_while(|((_, old), new)| old == new)
+        .last()
+        .map_or(0, |((index, c), _)| index + c.len_utf8());
+
+    let mut remaining_prefix = prefix_len;
+    let mut remaining_tail = new.len() - prefix_len;
+    for (_, normalized_len) in char_map.iter_mut() {
+        let len = *normalized_len as usize;
+        if remaining_prefix >= len {
+            remaining_prefix -= len;
+        } else {
+            *normalized_len = (remaining_prefix + remaining_tail).try_into().ok()?;
+            remaining_prefix = 0;
+            remaining_tail = 0;
+        }
+    }
+
+    // the new lemma is longer than the original one, give the remaining bytes to the last character.
+    if remaining_tail > 0 {
+        let (_, normalized_len) = char_map.last_mut()?;
+        *normalized_len = (*normalized_len as usize + remaining_tail).try_into().ok()?;
+    }
+
+    Some(())
+}
+
 pub trait CharNormalizer: Sync + Send {
     fn normalize_char(&self, c: char) -> Option<CharOrStr>;
 
@@ -263,20 +557,12 @@ impl Normalize for Token<'_> {
     ///
     /// A Latin `Token` would not be normalized the same as a Chinese `Token`.
     fn normalize(mut self, options: &NormalizerOption) -> Self::Item {
-        for normalizer in NORMALIZERS.iter() {
+        for normalizer in options.normalizers().iter(options.lossy) {
             if normalizer.should_normalize(&self) {
                 self = normalizer.normalize(self, options);
             }
         }
 
-        if options.lossy {
-            for normalizer in LOSSY_NORMALIZERS.iter() {
-                if normalizer.should_normalize(&self) {
-                    self = normalizer.normalize(self, options);
-                }
-            }
-        }
-
         self
     }
 }
@@ -287,16 +573,10 @@ impl<'o> Normalize for &'o str {
     /// Normalize an str.
     fn normalize(self, options: &NormalizerOption) -> Self::Item {
         let mut normalized = Token { lemma: Cow::Borrowed(self), ..Default::default() };
-        for normalizer in NORMALIZERS.iter() {
+        for normalizer in options.normalizers().iter(options.lossy) {
             normalized = normalizer.normalize(normalized, options);
         }
 
-        if options.lossy {
-            for normalizer in LOSSY_NORMALIZERS.iter() {
-                normalized = normalizer.normalize(normalized, options);
-            }
-        }
-
         normalized.lemma
     }
 }
@@ -307,28 +587,45 @@ mod test {
 
     use crate::normalizer::quote::QuoteNormalizer;
     use crate::normalizer::{
-        CompatibilityDecompositionNormalizer, LowercaseNormalizer, Normalizer,
+        realign_char_map, replace_lemma, CharNormalizer, CharOrStr, Classifier, ClassifierOption,
+        CompatibilityDecompositionNormalizer, LowercaseNormalizer, NonspacingMarkNormalizer,
+        Normalizer, NormalizerOption, NormalizerPipeline,
+    };
+    use crate::{Normalize, Script, Token, TokenKind};
+
+    /// Options used by default by `test_normalizer!`.
+    pub(crate) const TEST_NORMALIZER_OPTIONS: NormalizerOption = NormalizerOption {
+        create_char_map: true,
+        classifier: ClassifierOption::new(),
+        lossy: true,
+        normalizers: None,
     };
-    use crate::Token;
 
     macro_rules! test_normalizer {
         ($normalizer:expr, $tokens:expr, $normalizer_result:expr, $global_result:expr) => {
+            test_normalizer!(
+                $normalizer,
+                $tokens,
+                $normalizer_result,
+                $global_result,
+                crate::normalizer::test::TEST_NORMALIZER_OPTIONS
+            );
+        };
+        ($normalizer:expr, $tokens:expr, $normalizer_result:expr, $global_result:expr, $options:expr) => {
             use super::*;
             use crate::{Token, Normalize, StaticToken};
             use fst::Set;
 
-            const TEST_NORMALIZER_OPTIONS: NormalizerOption = NormalizerOption {
-                create_char_map: true,
-                lossy: true,
-                classifier: crate::normalizer::ClassifierOption { stop_words: None, separators: None },
-            };
+            fn test_normalizer_options() -> NormalizerOption<'static> {
+                $options
+            }
 
             #[test]
             fn normalizer_normalize() {
                 let normalized_tokens: Vec<_> = $tokens
                     .into_iter()
                     .map(|token| if Normalizer::should_normalize(&$normalizer, &token) {
-                        $normalizer.normalize(token, &TEST_NORMALIZER_OPTIONS)
+                        $normalizer.normalize(token, &test_normalizer_options())
                     } else {
                         token
                     })
@@ -348,7 +645,8 @@ it's probably due to a bug in the normalizer or a mistake in the provided normal
 
             #[test]
             fn global_normalize() {
-                let normalized_tokens: Vec<_> = $tokens.into_iter().map(|t| t.normalize(&TEST_NORMALIZER_OPTIONS)).collect();
+                let options = test_normalizer_options();
+                let normalized_tokens: Vec<_> = $tokens.into_iter().map(|t| t.normalize(&options)).collect();
                 assert_eq!(
                     &normalized_tokens[..],
                     $global_result,
@@ -374,7 +672,9 @@ Make sure that normalized tokens are valid or change the trigger condition of th
                     classifier:  crate::normalizer::ClassifierOption {
                         stop_words: Some(stop_words),
                         separators: Some(separators.as_slice()),
-                    }
+                        ..Default::default()
+                    },
+                    ..test_normalizer_options()
                 };
 
                 let normalized_token = token.normalize(&normalizer_option);
@@ -384,6 +684,116 @@ Make sure that normalized tokens are valid or change the trigger condition of th
     }
     pub(crate) use test_normalizer;
 
+    struct UppercaseNormalizer;
+
+    impl CharNormalizer for UppercaseNormalizer {
+        fn normalize_char(&self, c: char) -> Option<CharOrStr> {
+            Some(c.to_uppercase().collect::<String>().into())
+        }
+
+        fn should_normalize(&self, token: &Token) -> bool {
+            token.is_word()
+        }
+    }
+
+    #[test]
+    fn custom_pipeline() {
+        let mut normalizers = NormalizerPipeline::new();
+        assert!(normalizers.remove::<NonspacingMarkNormalizer>());
+        assert!(!normalizers.remove::<NonspacingMarkNormalizer>());
+        assert!(normalizers.insert_after::<Classifier, _>(UppercaseNormalizer));
+        assert!(normalizers.contains::<UppercaseNormalizer>());
+
+        let options = NormalizerOption {
+            lossy: true,
+            normalizers: Some(Cow::Borrowed(&normalizers)),
+            ..Default::default()
+        };
+
+        // the uppercase normalizer is applied before the lowercase one.
+        let token =
+            Token { lemma: Cow::Borrowed("Maïs"), script: Script::Latin, ..Default::default() }
+                .normalize(&options);
+        assert_eq!(token.lemma(), "mai\u{308}s");
+        assert!(token.is_word());
+
+        // reorder the pipeline to apply the uppercase normalizer last.
+        assert!(normalizers.remove::<UppercaseNormalizer>());
+        normalizers.push_lossy(UppercaseNormalizer);
+        let options = NormalizerOption {
+            lossy: true,
+            normalizers: Some(Cow::Borrowed(&normalizers)),
+            ..Default::default()
+        };
+        let token =
+            Token { lemma: Cow::Borrowed("Maïs"), script: Script::Latin, ..Default::default() }
+                .normalize(&options);
+        assert_eq!(token.lemma(), "MAI\u{308}S");
+
+        // the lossy normalizers are skipped.
+        let options = NormalizerOption { lossy: false, ..options };
+        let token =
+            Token { lemma: Cow::Borrowed("Maïs"), script: Script::Latin, ..Default::default() }
+                .normalize(&options);
+        assert_eq!(token.lemma(), "Mai\u{308}s");
+    }
+
+    #[test]
+    fn empty_pipeline() {
+        let normalizers = NormalizerPipeline::empty();
+        assert!(normalizers.is_empty());
+
+        let options = NormalizerOption {
+            lossy: true,
+            normalizers: Some(Cow::Borrowed(&normalizers)),
+            ..Default::default()
+        };
+        let token =
+            Token { lemma: Cow::Borrowed("Maïs"), script: Script::Latin, ..Default::default() }
+                .normalize(&options);
+        assert_eq!(token.lemma(), "Maïs");
+        assert_eq!(token.kind, TokenKind::Unknown);
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn lossy_normalizers() {
+        use crate::normalizer::{LOSSY_NORMALIZERS, NORMALIZERS};
+
+        let lossy = NORMALIZERS.normalizers.iter().filter(|entry| entry.lossy).count();
+        assert_eq!(LOSSY_NORMALIZERS.len(), lossy);
+
+        let token =
+            Token { lemma: Cow::Borrowed("Maïs"), script: Script::Latin, ..Default::default() };
+        let token = LOSSY_NORMALIZERS.iter().fold(token, |token, normalizer| {
+            normalizer.normalize(token, &NormalizerOption::default())
+        });
+        assert_eq!(token.lemma(), "maïs");
+    }
+
+    #[test]
+    fn realign_char_map_on_expansion() {
+        let mut char_map = vec![(1, 1), (1, 1)];
+        assert_eq!(realign_char_map(&mut char_map, "ab", "abcd"), Some(()));
+        assert_eq!(char_map, [(1, 1), (1, 3)]);
+
+        let mut char_map = vec![(2, 2), (1, 1)];
+        assert_eq!(realign_char_map(&mut char_map, "æb", "aeb"), Some(()));
+        assert_eq!(char_map, [(2, 3), (1, 0)]);
+
+        let mut char_map = vec![(1, 1)];
+        assert_eq!(realign_char_map(&mut char_map, "a", &"b".repeat(300)), None);
+    }
+
+    #[test]
+    fn drop_char_map_on_overflow() {
+        let options = NormalizerOption { create_char_map: true, ..NormalizerOption::new() };
+        let mut token = Token { lemma: Cow::Borrowed("a"), ..Default::default() };
+        replace_lemma(&mut token, "b".repeat(300), &options);
+        assert_eq!(token.lemma(), "b".repeat(300));
+        assert_eq!(token.char_map, None);
+    }
+
     #[test]
     fn split_at() {
         fn display_token<N>(token: &Token) {
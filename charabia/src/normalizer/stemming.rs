@@ -0,0 +1,268 @@
+use std::collections::HashMap;
+use std::sync::LazyLock;
+
+use rust_stemmers::{Algorithm, Stemmer};
+use unicode_normalization::{is_nfc, UnicodeNormalization};
+
+use super::{replace_lemma, Normalizer, NormalizerOption};
+use crate::detection::{Language, Script};
+use crate::Token;
+
+/// A [`Normalizer`] reducing inflected words to their stem.
+///
+/// This Normalizer uses [`rust_stemmers`] internally to apply the [Snowball](https://snowballstem.org/) algorithm
+/// corresponding to the [`Language`] of the provided token, for instance `running` and `runs` both become `run`.
+/// Tokens without a detected `Language` or with a `Language` that doesn't have a stemming algorithm are not changed.
+///
+/// This normalizer isn't part of the default [`NORMALIZERS`](super::NORMALIZERS),
+/// see [`TokenizerBuilder::stemming`](crate::TokenizerBuilder::stemming),
+/// a tokenizer using it detects the `Language` of every token, an allow list restricts the detected languages.
+///
+/// [`rust_stemmers`]: https://docs.rs/rust-stemmers/latest/rust_stemmers/
+#[derive(Debug, Clone, Copy, Default)]
+pub struct StemmingNormalizer;
+
+impl Normalizer for StemmingNormalizer {
+    fn normalize<'o>(&self, mut token: Token<'o>, options: &NormalizerOption) -> Token<'o> {
+        let Some(stemmer) = token.language.and_then(|language| STEMMERS.get(&language)) else {
+            return token;
+        };
+
+        // Snowball algorithms expect composed characters,
+        // so the lemma is recomposed before stemming and decomposed back afterward.
+        let lemma = token.lemma.as_ref();
+        let stemmed = if is_nfc(lemma) {
+            stemmer.stem(lemma).into_owned()
+        } else {
+            let composed: String = lemma.nfc().collect();
+            stemmer.stem(&composed).nfd().collect()
+        };
+
+        if stemmed != lemma {
+            replace_lemma(&mut token, stemmed, options);
+        }
+
+        token
+    }
+
+    fn should_normalize(&self, token: &Token) -> bool {
+        token.is_word()
+            && matches!(token.script, Script::Latin | Script::Cyrillic)
+            && token.language.is_some_and(|language| STEMMERS.contains_key(&language))
+    }
+}
+
+/// Snowball algorithm of each supported [`Language`].
+const ALGORITHMS: [(Language, Algorithm); 12] = [
+    (Language::Eng, Algorithm::English),
+    (Language::Fra, Algorithm::French),
+    (Language::Deu, Algorithm::German),
+    (Language::Spa, Algorithm::Spanish),
+    (Language::Ita, Algorithm::Italian),
+    (Language::Por, Algorithm::Portuguese),
+    (Language::Nld, Algorithm::Dutch),
+    (Language::Swe, Algorithm::Swedish),
+    (Language::Dan, Algorithm::Danish),
+    (Language::Nob, Algorithm::Norwegian),
+    (Language::Fin, Algorithm::Finnish),
+    (Language::Rus, Algorithm::Russian),
+];
+
+/// Stemmer of each supported [`Language`], created once and shared by every token.
+static STEMMERS: LazyLock<HashMap<Language, Stemmer>> = LazyLock::new(|| {
+    ALGORITHMS
+        .into_iter()
+        .map(|(language, algorithm)| (language, Stemmer::create(algorithm)))
+        .collect()
+});
+
+#[cfg(test)]
+mod test {
+    use std::borrow::Cow::{self, Owned};
+
+    use crate::normalizer::test::{test_normalizer, TEST_NORMALIZER_OPTIONS};
+    use crate::normalizer::{
+        NonspacingMarkNormalizer, Normalizer, NormalizerOption, NormalizerPipeline, NORMALIZERS,
+    };
+    use crate::token::TokenKind;
+
+    fn pipeline() -> NormalizerPipeline {
+        let mut normalizers = NormalizerPipeline::new();
+        normalizers.insert_before::<NonspacingMarkNormalizer, _>(StemmingNormalizer);
+        normalizers
+    }
+
+    // base tokens to normalize.
+    fn tokens() -> Vec<Token<'static>> {
+        vec![
+            Token {
+                lemma: Owned("running".to_string()),
+                char_end: 7,
+                byte_end: 7,
+                kind: TokenKind::Word,
+                script: Script::Latin,
+                language: Some(Language::Eng),
+                ..Default::default()
+            },
+            Token {
+                lemma: Owned("happy".to_string()),
+                char_end: 5,
+                byte_end: 5,
+                kind: TokenKind::Word,
+                script: Script::Latin,
+                language: Some(Language::Eng),
+                ..Default::default()
+            },
+            Token {
+                lemma: Owned("mangées".to_string()),
+                char_end: 7,
+                byte_end: 8,
+                kind: TokenKind::Word,
+                script: Script::Latin,
+                language: Some(Language::Fra),
+                ..Default::default()
+            },
+            Token {
+                lemma: Owned("книги".to_string()),
+                char_end: 5,
+                byte_end: 10,
+                kind: TokenKind::Word,
+                script: Script::Cyrillic,
+                language: Some(Language::Rus),
+                ..Default::default()
+            },
+            Token {
+                lemma: Owned("running".to_string()),
+                char_end: 7,
+                byte_end: 7,
+                kind: TokenKind::Word,
+                script: Script::Latin,
+                language: None,
+                ..Default::default()
+            },
+        ]
+    }
+
+    // expected result of the current Normalizer.
+    fn normalizer_result() -> Vec<Token<'static>> {
+        vec![
+            Token {
+                lemma: Owned("run".to_string()),
+                char_end: 7,
+                byte_end: 7,
+                char_map: Some(vec![(1, 1), (1, 1), (1, 1), (1, 0), (1, 0), (1, 0), (1, 0)]),
+                kind: TokenKind::Word,
+                script: Script::Latin,
+                language: Some(Language::Eng),
+                ..Default::default()
+            },
+            Token {
+                lemma: Owned("happi".to_string()),
+                char_end: 5,
+                byte_end: 5,
+                char_map: Some(vec![(1, 1), (1, 1), (1, 1), (1, 1), (1, 1)]),
+                kind: TokenKind::Word,
+                script: Script::Latin,
+                language: Some(Language::Eng),
+                ..Default::default()
+            },
+            Token {
+                lemma: Owned("mang".to_string()),
+                char_end: 7,
+                byte_end: 8,
+                char_map: Some(vec![(1, 1), (1, 1), (1, 1), (1, 1), (2, 0), (1, 0), (1, 0)]),
+                kind: TokenKind::Word,
+                script: Script::Latin,
+                language: Some(Language::Fra),
+                ..Default::default()
+            },
+            Token {
+                lemma: Owned("книг".to_string()),
+                char_end: 5,
+                byte_end: 10,
+                char_map: Some(vec![(2, 2), (2, 2), (2, 2), (2, 2), (2, 0)]),
+                kind: TokenKind::Word,
+                script: Script::Cyrillic,
+                language: Some(Language::Rus),
+                ..Default::default()
+            },
+            Token {
+                lemma: Owned("running".to_string()),
+                char_end: 7,
+                byte_end: 7,
+                kind: TokenKind::Word,
+                script: Script::Latin,
+                language: None,
+                ..Default::default()
+            },
+        ]
+    }
+
+    // expected result of the complete Normalizer pieline.
+    fn normalized_tokens() -> Vec<Token<'static>> {
+        vec![
+            Token {
+                lemma: Owned("run".to_string()),
+                char_end: 7,
+                byte_end: 7,
+                char_map: Some(vec![(1, 1), (1, 1), (1, 1), (1, 0), (1, 0), (1, 0), (1, 0)]),
+                kind: TokenKind::Word,
+                script: Script::Latin,
+                language: Some(Language::Eng),
+                ..Default::default()
+            },
+            Token {
+                lemma: Owned("happi".to_string()),
+                char_end: 5,
+                byte_end: 5,
+                char_map: Some(vec![(1, 1), (1, 1), (1, 1), (1, 1), (1, 1)]),
+                kind: TokenKind::Word,
+                script: Script::Latin,
+                language: Some(Language::Eng),
+                ..Default::default()
+            },
+            Token {
+                lemma: Owned("mang".to_string()),
+                char_end: 7,
+                byte_end: 8,
+                char_map: Some(vec![(1, 1), (1, 1), (1, 1), (1, 1), (2, 0), (1, 0), (1, 0)]),
+                kind: TokenKind::Word,
+                script: Script::Latin,
+                language: Some(Language::Fra),
+                ..Default::default()
+            },
+            Token {
+                lemma: Owned("книг".to_string()),
+                char_end: 5,
+                byte_end: 10,
+                char_map: Some(vec![(2, 2), (2, 2), (2, 2), (2, 2), (2, 0)]),
+                kind: TokenKind::Word,
+                script: Script::Cyrillic,
+                language: Some(Language::Rus),
+                ..Default::default()
+            },
+            Token {
+                lemma: Owned("running".to_string()),
+                char_end: 7,
+                byte_end: 7,
+                kind: TokenKind::Word,
+                script: Script::Latin,
+                language: None,
+                ..Default::default()
+            },
+        ]
+    }
+
+    test_normalizer!(
+        StemmingNormalizer,
+        tokens(),
+        normalizer_result(),
+        normalized_tokens(),
+        NormalizerOption { normalizers: Some(Cow::Owned(pipeline())), ..TEST_NORMALIZER_OPTIONS }
+    );
+
+    #[test]
+    fn disabled_by_default() {
+        assert!(!NORMALIZERS.contains::<StemmingNormalizer>());
+    }
+}
@@ -1,4 +1,5 @@
-use std::sync::LazyLock;
+use std::io::BufRead;
+use std::sync::{Arc, LazyLock};
 
 use jieba_rs::Jieba;
 
@@ -10,6 +11,85 @@ use crate::segmenter::Segmenter;
 /// without HMM feature.
 pub struct ChineseSegmenter;
 
+/// Word added to the dictionary of a [`ChineseUserDictSegmenter`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChineseUserWord<'a> {
+    /// The word to add.
+    pub word: &'a str,
+    /// The frequency of the word, if `None` a frequency high enough to segment the word is computed.
+    pub freq: Option<usize>,
+    /// The part of speech tag of the word.
+    pub tag: Option<&'a str>,
+}
+
+/// Segmentation of the Chinese text by the [`ChineseUserDictSegmenter`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ChineseSegmentationMode {
+    /// The most likely words of the dictionary, without overlap.
+    Precise,
+    /// The precise words, the long ones are split into the shorter words of the dictionary they contain.
+    ///
+    /// This is the segmentation of the [`ChineseSegmenter`].
+    #[default]
+    Search,
+    /// All the words of the dictionary found in the text, overlapping each other,
+    /// the single characters are only kept when no longer word contains them.
+    Full,
+    /// The precise words, the words missing from the dictionary are discovered using a Hidden Markov Model.
+    Hmm,
+}
+
+/// Chinese Script specialized [`Segmenter`] extending the [`Jieba`] dictionary with user words.
+///
+/// Unlike [`ChineseSegmenter`], this Segmenter owns its dictionary,
+/// so the user words only impact the tokenizers using this Segmenter.
+/// The dictionary is only copied when the first user words are added.
+#[derive(Debug, Clone)]
+pub struct ChineseUserDictSegmenter {
+    jieba: Arc<Jieba>,
+    mode: ChineseSegmentationMode,
+}
+
+impl ChineseUserDictSegmenter {
+    /// Creates a Segmenter using the default dictionary extended with the given words.
+    pub fn new<'a>(words: impl IntoIterator<Item = ChineseUserWord<'a>>) -> Self {
+        let mut segmenter = Self::default();
+        segmenter.add_words(words);
+        segmenter
+    }
+
+    /// Sets the [`ChineseSegmentationMode`], [`ChineseSegmentationMode::Search`] by default.
+    pub fn with_mode(mut self, mode: ChineseSegmentationMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Adds words to the dictionary of the Segmenter.
+    pub fn add_words<'a>(&mut self, words: impl IntoIterator<Item = ChineseUserWord<'a>>) {
+        let mut words = words.into_iter().peekable();
+        if words.peek().is_none() {
+            return;
+        }
+
+        let jieba = Arc::make_mut(&mut self.jieba);
+        for ChineseUserWord { word, freq, tag } in words {
+            jieba.add_word(word, freq, tag);
+        }
+    }
+
+    /// Adds the words of a dictionary using the Jieba format,
+    /// each line contains a word followed by an optional frequency and an optional tag separated by whitespaces.
+    pub fn load_dict<R: BufRead>(&mut self, dict: &mut R) -> Result<(), jieba_rs::Error> {
+        Arc::make_mut(&mut self.jieba).load_dict(dict)
+    }
+}
+
+impl Default for ChineseUserDictSegmenter {
+    fn default() -> Self {
+        Self { jieba: JIEBA.clone(), mode: ChineseSegmentationMode::default() }
+    }
+}
+
 fn next_gram<const N: usize>(s: &str) -> Option<&str> {
     match s.char_indices().nth(N - 1) {
         Some((byte_index, c)) => Some(&s[0..(byte_index + c.len_utf8())]),
@@ -17,7 +97,7 @@ fn next_gram<const N: usize>(s: &str) -> Option<&str> {
     }
 }
 
-fn cut_for_search<'a>(s: &'a str) -> Box<dyn Iterator<Item = &'a str> + 'a> {
+fn cut_for_search<'a>(jieba: &Jieba, s: &'a str) -> Box<dyn Iterator<Item = &'a str> + 'a> {
     if s.chars().count() <= 2 {
         return Box::new(std::iter::once(s));
     }
@@ -27,12 +107,12 @@ fn cut_for_search<'a>(s: &'a str) -> Box<dyn Iterator<Item = &'a str> + 'a> {
     let mut subwords = Vec::new();
     let mut index = 0;
     loop {
-        if let Some(bigram) = next_gram::<2>(&s[index..]).filter(|sub| JIEBA.has_word(sub)) {
+        if let Some(bigram) = next_gram::<2>(&s[index..]).filter(|sub| jieba.has_word(sub)) {
             // valid bigram, register it and advance by two characters.
             // greedy thinking: do bigram first, maybe we can get more words
             index += bigram.len();
             subwords.push(bigram);
-        } else if let Some(trigram) = next_gram::<3>(&s[index..]).filter(|sub| JIEBA.has_word(sub))
+        } else if let Some(trigram) = next_gram::<3>(&s[index..]).filter(|sub| jieba.has_word(sub))
         {
             // valid trigram, register it and advance by three characters.
             index += trigram.len();
@@ -49,18 +129,61 @@ fn cut_for_search<'a>(s: &'a str) -> Box<dyn Iterator<Item = &'a str> + 'a> {
     Box::new(subwords.into_iter())
 }
 
-impl Segmenter for ChineseSegmenter {
-    fn segment_str<'o>(&self, to_segment: &'o str) -> Box<dyn Iterator<Item = &'o str> + 'o> {
-        let segmented: Vec<&str> = JIEBA
+/// Returns all the words of the dictionary found in `s`,
+/// without the single characters that are part of a longer word.
+fn cut_all<'a>(jieba: &Jieba, s: &'a str) -> Vec<&'a str> {
+    let words = jieba.cut_all(s);
+    // the bytes covered by the words containing several characters.
+    let mut covered = vec![false; s.len()];
+    for word in words.iter().filter(|word| word.chars().nth(1).is_some()) {
+        let start = word.as_ptr() as usize - s.as_ptr() as usize;
+        covered[start..start + word.len()].fill(true);
+    }
+
+    words
+        .into_iter()
+        .filter(|word| {
+            let start = word.as_ptr() as usize - s.as_ptr() as usize;
+            word.chars().nth(1).is_some() || !covered[start]
+        })
+        .collect()
+}
+
+fn segment<'o>(
+    jieba: &Jieba,
+    mode: ChineseSegmentationMode,
+    to_segment: &'o str,
+) -> Box<dyn Iterator<Item = &'o str> + 'o> {
+    let segmented: Vec<&str> = match mode {
+        ChineseSegmentationMode::Precise => jieba.cut(to_segment, false),
+        ChineseSegmentationMode::Search => jieba
             .cut(to_segment, false) // disable Hidden Markov Models.
             .into_iter()
-            .flat_map(|x| cut_for_search(x))
-            .collect();
-        Box::new(segmented.into_iter())
+            .flat_map(|x| cut_for_search(jieba, x))
+            .collect(),
+        ChineseSegmentationMode::Full => cut_all(jieba, to_segment),
+        ChineseSegmentationMode::Hmm => jieba.cut(to_segment, true),
+    };
+    Box::new(segmented.into_iter())
+}
+
+impl Segmenter for ChineseSegmenter {
+    fn segment_str<'o>(&self, to_segment: &'o str) -> Box<dyn Iterator<Item = &'o str> + 'o> {
+        segment(&JIEBA, ChineseSegmentationMode::Search, to_segment)
     }
 }
 
-static JIEBA: LazyLock<Jieba> = LazyLock::new(Jieba::new);
+impl Segmenter for ChineseUserDictSegmenter {
+    fn segment_str<'o>(&self, to_segment: &'o str) -> Box<dyn Iterator<Item = &'o str> + 'o> {
+        segment(&self.jieba, self.mode, to_segment)
+    }
+
+    fn overlapping_segments(&self) -> bool {
+        self.mode == ChineseSegmentationMode::Full
+    }
+}
+
+static JIEBA: LazyLock<Arc<Jieba>> = LazyLock::new(|| Arc::new(Jieba::new()));
 
 #[cfg(test)]
 mod test {
@@ -344,4 +467,58 @@ mod test {
         let words: Vec<&str> = seg.segment_str("我从2025年开始学习Rust语言。").collect();
         assert_eq!(words, vec!["我", "从", "2025", "年", "开始", "学习", "Rust", "语言", "。"]);
     }
+
+    #[test]
+    fn user_words() {
+        let words = [ChineseUserWord { word: "哼着", freq: Some(100_000), tag: Some("v") }];
+        let seg = ChineseUserDictSegmenter::new(words);
+        let words: Vec<&str> = seg.segment_str("像是哼着小曲的少年").collect();
+        assert_eq!(words, vec!["像是", "哼着", "小曲", "的", "少年"]);
+
+        // the user words are also used to split the long words.
+        let words: Vec<&str> = cut_for_search(&seg.jieba, "哼着小曲").collect();
+        assert_eq!(words, vec!["哼着", "小曲"]);
+        // the default dictionary is not impacted.
+        let words: Vec<&str> = ChineseSegmenter.segment_str("像是哼着小曲的少年").collect();
+        assert!(!words.contains(&"哼着"));
+    }
+
+    #[test]
+    fn load_user_dict() {
+        let mut seg = ChineseUserDictSegmenter::new([]);
+        seg.load_dict(&mut "哼着 100000 v\n".as_bytes()).unwrap();
+        let words: Vec<&str> = seg.segment_str("像是哼着小曲的少年").collect();
+        assert!(words.contains(&"哼着"));
+    }
+
+    #[test]
+    fn segmentation_modes() {
+        fn segment_with(mode: ChineseSegmentationMode, text: &str) -> Vec<&str> {
+            ChineseUserDictSegmenter::default().with_mode(mode).segment_str(text).collect()
+        }
+
+        let text = "我来到北京清华大学";
+        assert_eq!(
+            segment_with(ChineseSegmentationMode::Precise, text),
+            ["我", "来到", "北京", "清华大学"]
+        );
+        assert_eq!(
+            segment_with(ChineseSegmentationMode::Search, text),
+            ["我", "来到", "北京", "清华", "大学"]
+        );
+        assert_eq!(
+            segment_with(ChineseSegmentationMode::Full, text),
+            ["我", "来到", "北京", "清华", "清华大学", "华大", "大学"]
+        );
+
+        let text = "他来到了网易杭研大厦";
+        assert_eq!(
+            segment_with(ChineseSegmentationMode::Precise, text),
+            ["他", "来到", "了", "网易", "杭", "研", "大厦"]
+        );
+        assert_eq!(
+            segment_with(ChineseSegmentationMode::Hmm, text),
+            ["他", "来到", "了", "网易", "杭研", "大厦"]
+        );
+    }
 }
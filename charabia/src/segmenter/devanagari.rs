@@ -0,0 +1,121 @@
+use slice_group_by::StrGroupBy;
+
+use super::Segmenter;
+
+/// Devanagari specialized [`Segmenter`].
+///
+/// Hindi, Marathi and Nepali words are separated by spaces and punctuation, including the danda `।`,
+/// which are already handled by the separators.
+/// This Segmenter additionally splits the words from the attached numbers and symbols, like `२०२४में` into `२०२४` and `में`,
+/// while keeping the vowel signs, the virama and the zero-width joiners of the conjuncts inside the words.
+pub struct DevanagariSegmenter;
+
+impl Segmenter for DevanagariSegmenter {
+    fn segment_str<'o>(&self, to_segment: &'o str) -> Box<dyn Iterator<Item = &'o str> + 'o> {
+        Box::new(to_segment.linear_group_by_key(is_devanagari_letter))
+    }
+}
+
+/// Returns true if the character is a Devanagari letter, a combining sign or a joiner used in conjuncts.
+fn is_devanagari_letter(c: char) -> bool {
+    matches!(c,
+        '\u{0900}'..='\u{0963}' // letters, vowel signs, nukta and virama
+        | '\u{0971}'..='\u{097F}' // additional letters
+        | '\u{1CD0}'..='\u{1CFF}' // vedic extensions
+        | '\u{A8E0}'..='\u{A8FF}' // devanagari extended
+        | '\u{200C}' | '\u{200D}' // zero-width non-joiner and joiner
+    )
+}
+
+// Test the segmenter:
+#[cfg(test)]
+mod test {
+    use crate::segmenter::test::test_segmenter;
+
+    // Original version of the text.
+    const TEXT: &str = "हिन्दी भारत की राजभाषा है। मैं क़लम और फ़ोन २०२४में ख़रीदूँगा, लक्‍ष्य साफ़ है 123 456";
+
+    // Segmented version of the text.
+    const SEGMENTED: &[&str] = &[
+        "हिन्दी",
+        " ",
+        "भारत",
+        " ",
+        "की",
+        " ",
+        "राजभाषा",
+        " ",
+        "है",
+        "।",
+        " ",
+        "मैं",
+        " ",
+        "क़लम",
+        " ",
+        "और",
+        " ",
+        "फ़ोन",
+        " ",
+        "२०२४",
+        "में",
+        " ",
+        "ख़रीदूँगा",
+        ", ",
+        "लक्‍ष्य",
+        " ",
+        "साफ़",
+        " ",
+        "है",
+        " ",
+        "123",
+        " ",
+        "456",
+    ];
+
+    // Segmented and normalized version of the text.
+    const TOKENIZED: &[&str] = &[
+        "हिन्दी",
+        " ",
+        "भारत",
+        " ",
+        "की",
+        " ",
+        "राजभाषा",
+        " ",
+        "है",
+        "।",
+        " ",
+        "मैं",
+        " ",
+        "कलम",
+        " ",
+        "और",
+        " ",
+        "फोन",
+        " ",
+        "२०२४",
+        "में",
+        " ",
+        "खरीदूंगा",
+        ", ",
+        "लक्ष्य",
+        " ",
+        "साफ",
+        " ",
+        "है",
+        " ",
+        "123",
+        " ",
+        "456",
+    ];
+
+    // Macro that run several tests on the Segmenter.
+    test_segmenter!(
+        DevanagariSegmenter,
+        TEXT,
+        SEGMENTED,
+        TOKENIZED,
+        Script::Devanagari,
+        Language::Hin
+    );
+}
@@ -1,58 +1,95 @@
+use std::path::Path;
 use std::sync::LazyLock;
 
-use lindera::dictionary::{load_dictionary_from_kind, DictionaryKind};
-use lindera::mode::{Mode, Penalty};
-use lindera::segmenter::Segmenter as LinderaSegmenter;
+use lindera::dictionary::DictionaryKind;
+use lindera::error::LinderaError;
 use lindera::tokenizer::Tokenizer;
-#[cfg(feature = "japanese-segmentation-ipadic")]
-use lindera::Penalty;
 
+use super::lindera_config::{
+    build_tokenizer, load_tokenizer, segment, segment_with_metadata, DetailsLayout, LinderaMode,
+};
 use crate::segmenter::Segmenter;
+use crate::token::TokenMetadata;
 
 /// Japanese specialized [`Segmenter`].
 ///
 /// This Segmenter uses lindera internally to segment the provided text.
 pub struct JapaneseSegmenter;
 
-static LINDERA: LazyLock<Tokenizer> = LazyLock::new(|| {
-    #[cfg(all(feature = "japanese-segmentation-ipadic", feature = "japanese-segmentation-unidic"))]
-    compile_error!("Feature japanese-segmentation-ipadic and japanese-segmentation-unidic are mutually exclusive and cannot be enabled together");
+/// Japanese specialized [`Segmenter`] using its own lindera tokenizer,
+/// configured with a [`LinderaMode`] and an optional user dictionary.
+///
+/// The user dictionary is a CSV file in the lindera simple user dictionary format,
+/// each line contains exactly 3 columns in this order: the surface form, the part of speech and the reading of a word,
+/// for example `東京スカイツリー,カスタム名詞,トウキョウスカイツリー`.
+/// The part of speech comes before the reading, a file listing the reading in the second column must swap its columns,
+/// and a line with another number of columns fails the creation of the Segmenter.
+/// Its words, like product names and proper nouns, are kept whole by the segmentation.
+pub struct JapaneseUserDictSegmenter {
+    tokenizer: Tokenizer,
+}
 
-    #[cfg(feature = "japanese-segmentation-ipadic")]
-    {
-        let dictionary = load_dictionary_from_kind(DictionaryKind::IPADIC).unwrap();
-        let segmenter =
-            LinderaSegmenter::new(Mode::Decompose(Penalty::default()), dictionary, None);
-        Tokenizer::new(segmenter)
-    }
-    #[cfg(feature = "japanese-segmentation-unidic")]
-    {
-        let dictionary = load_dictionary_from_kind(DictionaryKind::UniDic).unwrap();
-        let segmenter =
-            LinderaSegmenter::new(Mode::Decompose(Penalty::default()), dictionary, None);
-        Tokenizer::new(segmenter)
+impl JapaneseUserDictSegmenter {
+    /// Creates a Segmenter using the given mode and the words of the optional user dictionary.
+    pub fn new(mode: LinderaMode, user_dictionary: Option<&Path>) -> Result<Self, LinderaError> {
+        let dictionary = LINDERA.segmenter.dictionary.clone();
+        let tokenizer = build_tokenizer(DICTIONARY_KIND, dictionary, mode, user_dictionary)?;
+        Ok(Self { tokenizer })
     }
-});
+}
+
+#[cfg(all(feature = "japanese-segmentation-ipadic", feature = "japanese-segmentation-unidic"))]
+compile_error!("Feature japanese-segmentation-ipadic and japanese-segmentation-unidic are mutually exclusive and cannot be enabled together");
+
+#[cfg(feature = "japanese-segmentation-ipadic")]
+const DICTIONARY_KIND: DictionaryKind = DictionaryKind::IPADIC;
+#[cfg(feature = "japanese-segmentation-unidic")]
+const DICTIONARY_KIND: DictionaryKind = DictionaryKind::UniDic;
+
+/// IPADIC details: part of speech, 3 subcategories, conjugation type and form, base form, reading and pronunciation.
+#[cfg(feature = "japanese-segmentation-ipadic")]
+const DETAILS_LAYOUT: DetailsLayout = DetailsLayout { base_form: Some(6), reading: Some(7) };
+/// UniDic details: part of speech, 3 subcategories, conjugation type and form, lexeme reading, lexeme,
+/// orthography and pronunciation of the surface form, then orthography of the base form.
+///
+/// The reading is the Katakana reading of the lexeme, like `トウキョウ` for `東京` and `タベル` for `食べ`,
+/// rather than the pronunciation of the surface form, like `トーキョー`.
+/// It is also the detail where the user dictionary stores the reading of its words.
+#[cfg(feature = "japanese-segmentation-unidic")]
+const DETAILS_LAYOUT: DetailsLayout = DetailsLayout { base_form: Some(10), reading: Some(6) };
+
+static LINDERA: LazyLock<Tokenizer> = LazyLock::new(|| load_tokenizer(DICTIONARY_KIND));
 
 impl Segmenter for JapaneseSegmenter {
     fn segment_str<'o>(&self, to_segment: &'o str) -> Box<dyn Iterator<Item = &'o str> + 'o> {
-        let tokens = LINDERA.tokenize(to_segment).unwrap();
-
-        let result: Vec<&'o str> = tokens
-            .into_iter()
-            .map(|token| {
-                let start = token.byte_start;
-                let end = token.byte_end;
-                &to_segment[start..end]
-            })
-            .collect();
+        segment(&LINDERA, to_segment)
+    }
+
+    fn segment_str_with_metadata<'o>(
+        &self,
+        to_segment: &'o str,
+    ) -> Box<dyn Iterator<Item = (&'o str, Option<TokenMetadata>)> + 'o> {
+        segment_with_metadata(&LINDERA, DETAILS_LAYOUT, to_segment)
+    }
+}
+
+impl Segmenter for JapaneseUserDictSegmenter {
+    fn segment_str<'o>(&self, to_segment: &'o str) -> Box<dyn Iterator<Item = &'o str> + 'o> {
+        segment(&self.tokenizer, to_segment)
+    }
 
-        Box::new(result.into_iter())
+    fn segment_str_with_metadata<'o>(
+        &self,
+        to_segment: &'o str,
+    ) -> Box<dyn Iterator<Item = (&'o str, Option<TokenMetadata>)> + 'o> {
+        segment_with_metadata(&self.tokenizer, DETAILS_LAYOUT, to_segment)
     }
 }
 
 #[cfg(test)]
 mod test {
+    use std::io::Write;
+
     use crate::segmenter::test::test_segmenter;
 
     const TEXT: &str = "関西国際空港限定トートバッグ すもももももももものうち 123 456";
@@ -158,9 +195,96 @@ mod test {
         &[]
     };
 
-    #[cfg(all(feature = "japanese-segmentation-ipadic", feature = "japanese-segmentation-unidic"))]
-    compile_error!("Feature japanese-segmentation-ipadic and japanese-segmentation-unidic are mutually exclusive and cannot be enabled together");
-
     // Macro that run several tests on the Segmenter.
     test_segmenter!(JapaneseSegmenter, TEXT, SEGMENTED, TOKENIZED, Script::Cj, Language::Jpn);
+
+    #[cfg(feature = "japanese-segmentation-ipadic")]
+    #[test]
+    fn modes() {
+        let text = "関西国際空港限定トートバッグ";
+        let segmenter = JapaneseUserDictSegmenter::new(LinderaMode::Normal, None).unwrap();
+        let words: Vec<&str> = segmenter.segment_str(text).collect();
+        assert!(words.contains(&"関西国際空港"));
+
+        let segmenter = JapaneseUserDictSegmenter::new(LinderaMode::default(), None).unwrap();
+        let words: Vec<&str> = segmenter.segment_str(text).collect();
+        assert!(!words.contains(&"関西国際空港"));
+    }
+
+    #[test]
+    fn user_dictionary() {
+        // the process id avoids sharing the file with the tests of other builds running concurrently.
+        let file_name = format!("charabia_japanese_user_dictionary_{}.csv", std::process::id());
+        let path = std::env::temp_dir().join(file_name);
+        let mut file = std::fs::File::create(&path).unwrap();
+        writeln!(file, "東京スカイツリー,カスタム名詞,トウキョウスカイツリー").unwrap();
+        drop(file);
+
+        let segmenter =
+            JapaneseUserDictSegmenter::new(LinderaMode::default(), Some(&path)).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        let words: Vec<&str> = segmenter.segment_str("東京スカイツリーの最寄り駅").collect();
+        assert_eq!(words[0], "東京スカイツリー");
+
+        // the part of speech is the second column and the reading the third one.
+        let (_, metadata) =
+            segmenter.segment_str_with_metadata("東京スカイツリーの最寄り駅").next().unwrap();
+        let metadata = metadata.unwrap();
+        assert_eq!(metadata.part_of_speech.as_deref(), Some("カスタム名詞"));
+        assert_eq!(metadata.reading.as_deref(), Some("トウキョウスカイツリー"));
+
+        let words: Vec<&str> =
+            JapaneseSegmenter.segment_str("東京スカイツリーの最寄り駅").collect();
+        assert_ne!(words[0], "東京スカイツリー");
+    }
+
+    #[test]
+    fn malformed_user_dictionary() {
+        let file_name =
+            format!("charabia_japanese_malformed_dictionary_{}.csv", std::process::id());
+        let path = std::env::temp_dir().join(file_name);
+        let mut file = std::fs::File::create(&path).unwrap();
+        // the reading is missing.
+        writeln!(file, "東京スカイツリー,カスタム名詞").unwrap();
+        drop(file);
+
+        let segmenter = JapaneseUserDictSegmenter::new(LinderaMode::default(), Some(&path));
+        std::fs::remove_file(&path).unwrap();
+        assert!(segmenter.is_err());
+
+        let path = std::env::temp_dir().join("charabia_missing_japanese_dictionary.csv");
+        assert!(JapaneseUserDictSegmenter::new(LinderaMode::default(), Some(&path)).is_err());
+    }
+
+    #[test]
+    fn metadata() {
+        let words: Vec<_> = JapaneseSegmenter
+            .segment_str_with_metadata("猫が食べた")
+            .map(|(word, metadata)| {
+                let metadata = metadata.unwrap();
+                (word, metadata.part_of_speech.unwrap(), metadata.base_form.unwrap())
+            })
+            .collect();
+
+        assert_eq!(words[0], ("猫", "名詞".to_string(), "猫".to_string()));
+        assert_eq!(words[1], ("が", "助詞".to_string(), "が".to_string()));
+        assert_eq!(words[2], ("食べ", "動詞".to_string(), "食べる".to_string()));
+    }
+
+    #[test]
+    fn reading() {
+        let readings: Vec<_> = JapaneseSegmenter
+            .segment_str_with_metadata("東京で食べた")
+            .map(|(word, metadata)| (word, metadata.unwrap().reading.unwrap()))
+            .collect();
+
+        // the reading is in Katakana, not the pronunciation `トーキョー`.
+        assert_eq!(readings[0], ("東京", "トウキョウ".to_string()));
+        assert_eq!(readings[1], ("で", "デ".to_string()));
+        // IPADIC reads the surface form while UniDic reads the lexeme.
+        #[cfg(feature = "japanese-segmentation-ipadic")]
+        assert_eq!(readings[2], ("食べ", "タベ".to_string()));
+        #[cfg(feature = "japanese-segmentation-unidic")]
+        assert_eq!(readings[2], ("食べ", "タベル".to_string()));
+    }
 }
@@ -1,42 +1,78 @@
+use std::path::Path;
 use std::sync::LazyLock;
 
-use lindera::dictionary::{load_dictionary_from_kind, DictionaryKind};
-use lindera::mode::{Mode, Penalty};
-use lindera::segmenter::Segmenter as LinderaSegmenter;
+use lindera::dictionary::DictionaryKind;
+use lindera::error::LinderaError;
 use lindera::tokenizer::Tokenizer;
 
+use super::lindera_config::{
+    build_tokenizer, load_tokenizer, segment, segment_with_metadata, DetailsLayout, LinderaMode,
+};
 use crate::segmenter::Segmenter;
+use crate::token::TokenMetadata;
 
 /// Korean specialized [`Segmenter`].
 ///
 /// This Segmenter uses lindera internally to segment the provided text.
 pub struct KoreanSegmenter;
 
-static LINDERA: LazyLock<Tokenizer> = LazyLock::new(|| {
-    let dictionary = load_dictionary_from_kind(DictionaryKind::KoDic).unwrap();
-    let segmenter = LinderaSegmenter::new(Mode::Decompose(Penalty::default()), dictionary, None);
-    Tokenizer::new(segmenter)
-});
+/// Korean specialized [`Segmenter`] using its own lindera tokenizer,
+/// configured with a [`LinderaMode`] and an optional user dictionary.
+///
+/// The user dictionary is a CSV file in the lindera simple user dictionary format,
+/// each line contains exactly 3 columns in this order: the surface form, the part of speech and the reading of a word,
+/// for example `하이퍼그래프,NNP,하이퍼그래프`.
+/// The part of speech comes before the reading, a file listing the reading in the second column must swap its columns,
+/// and a line with another number of columns fails the creation of the Segmenter.
+pub struct KoreanUserDictSegmenter {
+    tokenizer: Tokenizer,
+}
+
+impl KoreanUserDictSegmenter {
+    /// Creates a Segmenter using the given mode and the words of the optional user dictionary.
+    pub fn new(mode: LinderaMode, user_dictionary: Option<&Path>) -> Result<Self, LinderaError> {
+        let dictionary = LINDERA.segmenter.dictionary.clone();
+        let tokenizer = build_tokenizer(DictionaryKind::KoDic, dictionary, mode, user_dictionary)?;
+        Ok(Self { tokenizer })
+    }
+}
+
+/// ko-dic details: part of speech, semantic class, final consonant, reading, type,
+/// first and last part of speech, and expression.
+const DETAILS_LAYOUT: DetailsLayout = DetailsLayout { base_form: None, reading: Some(3) };
+
+static LINDERA: LazyLock<Tokenizer> = LazyLock::new(|| load_tokenizer(DictionaryKind::KoDic));
 
 impl Segmenter for KoreanSegmenter {
     fn segment_str<'o>(&self, to_segment: &'o str) -> Box<dyn Iterator<Item = &'o str> + 'o> {
-        let tokens = LINDERA.tokenize(to_segment).unwrap();
-
-        let result: Vec<&'o str> = tokens
-            .into_iter()
-            .map(|token| {
-                let start = token.byte_start;
-                let end = token.byte_end;
-                &to_segment[start..end]
-            })
-            .collect();
+        segment(&LINDERA, to_segment)
+    }
+
+    fn segment_str_with_metadata<'o>(
+        &self,
+        to_segment: &'o str,
+    ) -> Box<dyn Iterator<Item = (&'o str, Option<TokenMetadata>)> + 'o> {
+        segment_with_metadata(&LINDERA, DETAILS_LAYOUT, to_segment)
+    }
+}
+
+impl Segmenter for KoreanUserDictSegmenter {
+    fn segment_str<'o>(&self, to_segment: &'o str) -> Box<dyn Iterator<Item = &'o str> + 'o> {
+        segment(&self.tokenizer, to_segment)
+    }
 
-        Box::new(result.into_iter())
+    fn segment_str_with_metadata<'o>(
+        &self,
+        to_segment: &'o str,
+    ) -> Box<dyn Iterator<Item = (&'o str, Option<TokenMetadata>)> + 'o> {
+        segment_with_metadata(&self.tokenizer, DETAILS_LAYOUT, to_segment)
     }
 }
 
 #[cfg(test)]
 mod test {
+    use std::io::Write;
+
     use crate::segmenter::test::test_segmenter;
 
     const TEXT: &str = "한국어의형태해석을실시할수있습니다 123 456.";
@@ -79,4 +115,53 @@ mod test {
 
     // Macro that run several tests on the Segmenter.
     test_segmenter!(KoreanSegmenter, TEXT, SEGMENTED, TOKENIZED, Script::Hangul, Language::Kor);
+
+    #[test]
+    fn user_dictionary() {
+        // the process id avoids sharing the file with the tests of other builds running concurrently.
+        let file_name = format!("charabia_korean_user_dictionary_{}.csv", std::process::id());
+        let path = std::env::temp_dir().join(file_name);
+        let mut file = std::fs::File::create(&path).unwrap();
+        writeln!(file, "하이퍼그래프,NNP,하이퍼그래프").unwrap();
+        drop(file);
+
+        let segmenter = KoreanUserDictSegmenter::new(LinderaMode::Normal, Some(&path)).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        let words: Vec<&str> = segmenter.segment_str("하이퍼그래프를 사용합니다").collect();
+        assert_eq!(words[0], "하이퍼그래프");
+
+        // the part of speech is the second column and the reading the third one.
+        let (_, metadata) =
+            segmenter.segment_str_with_metadata("하이퍼그래프를 사용합니다").next().unwrap();
+        let metadata = metadata.unwrap();
+        assert_eq!(metadata.part_of_speech.as_deref(), Some("NNP"));
+        assert_eq!(metadata.reading.as_deref(), Some("하이퍼그래프"));
+    }
+
+    #[test]
+    fn malformed_user_dictionary() {
+        let file_name = format!("charabia_korean_malformed_dictionary_{}.csv", std::process::id());
+        let path = std::env::temp_dir().join(file_name);
+        let mut file = std::fs::File::create(&path).unwrap();
+        // the reading is missing.
+        writeln!(file, "하이퍼그래프,NNP").unwrap();
+        drop(file);
+
+        let segmenter = KoreanUserDictSegmenter::new(LinderaMode::Normal, Some(&path));
+        std::fs::remove_file(&path).unwrap();
+        assert!(segmenter.is_err());
+
+        let path = std::env::temp_dir().join("charabia_missing_korean_dictionary.csv");
+        assert!(KoreanUserDictSegmenter::new(LinderaMode::Normal, Some(&path)).is_err());
+    }
+
+    #[test]
+    fn metadata() {
+        let words: Vec<_> = KoreanSegmenter
+            .segment_str_with_metadata("한국어의")
+            .map(|(word, metadata)| (word, metadata.unwrap().part_of_speech.unwrap()))
+            .collect();
+
+        assert_eq!(words, [("한국어", "NNG".to_string()), ("의", "JKG".to_string())]);
+    }
 }
@@ -0,0 +1,86 @@
+use std::sync::LazyLock;
+
+use fst::raw::Fst;
+
+use crate::segmenter::utils::{BufferingStrategy, FstSegmenter};
+use crate::segmenter::Segmenter;
+
+/// Lao specialized [`Segmenter`].
+///
+/// This Segmenter uses a dictionary encoded as an FST to segment the provided text.
+/// The dictionary is only a sample of common words, the unknown sequences are segmented by character,
+/// keeping the vowel signs and tone marks with their consonant, see `dictionaries/txt/lao/README.md` for its origin.
+pub struct LaoSegmenter;
+
+static WORDS_FST: LazyLock<Fst<&[u8]>> = LazyLock::new(|| {
+    Fst::new(&include_bytes!("../../dictionaries/fst/lao/words.fst")[..]).unwrap()
+});
+
+static FST_SEGMENTER: LazyLock<FstSegmenter> = LazyLock::new(|| {
+    // the dictionary is incomplete, so the unknown sequences are segmented by cluster instead of being buffered
+    FstSegmenter::new(&WORDS_FST, BufferingStrategy::Cluster)
+});
+
+impl Segmenter for LaoSegmenter {
+    fn segment_str<'o>(&self, to_segment: &'o str) -> Box<dyn Iterator<Item = &'o str> + 'o> {
+        FST_SEGMENTER.segment_str(to_segment)
+    }
+}
+
+// Test the segmenter:
+#[cfg(test)]
+mod test {
+    use crate::segmenter::test::test_segmenter;
+
+    const TEXT: &str = "ສະບາຍດີ ຂ້ອຍຮຽນພາສາລາວຢູ່ວຽງຈັນ ເຂົາເປັນຄົນລາວ 123 456";
+
+    const SEGMENTED: &[&str] = &[
+        "ສະບາຍດີ",
+        " ",
+        "ຂ້ອຍ",
+        "ຮຽນ",
+        "ພາສາ",
+        "ລາວ",
+        "ຢູ່",
+        "ວຽງຈັນ",
+        " ",
+        "ເຂົາ",
+        "ເປັນ",
+        "ຄົນ",
+        "ລາວ",
+        " ",
+        "123",
+        " ",
+        "456",
+    ];
+
+    const TOKENIZED: &[&str] = &[
+        "ສະບາຍດີ",
+        " ",
+        "ຂ້ອຍ",
+        "ຮຽນ",
+        "ພາສາ",
+        "ລາວ",
+        "ຢູ່",
+        "ວຽງຈັນ",
+        " ",
+        "ເຂົາ",
+        "ເປັນ",
+        "ຄົນ",
+        "ລາວ",
+        " ",
+        "123",
+        " ",
+        "456",
+    ];
+
+    // Macro that run several tests on the Segmenter.
+    test_segmenter!(LaoSegmenter, TEXT, SEGMENTED, TOKENIZED, Script::Lao);
+
+    #[test]
+    fn unknown_words() {
+        // `ມະຫາວິທະຍາໄລ` is missing from the dictionary.
+        let segmented: Vec<_> = LaoSegmenter.segment_str("ມະຫາວິທະຍາໄລລາວ").collect();
+        assert_eq!(segmented, ["ມ", "ະ", "ຫ", "າ", "ວິ", "ທ", "ະ", "ຍ", "າ", "ໄ", "ລ", "ລາວ"]);
+    }
+}
@@ -0,0 +1,149 @@
+use std::path::Path;
+
+use lindera::dictionary::{
+    load_dictionary_from_kind, load_user_dictionary_from_csv, Dictionary, DictionaryKind,
+};
+use lindera::error::LinderaError;
+use lindera::mode::{Mode, Penalty};
+use lindera::segmenter::Segmenter as LinderaSegmenter;
+use lindera::tokenizer::Tokenizer;
+
+use crate::token::TokenMetadata;
+
+/// Segmentation mode of the lindera based segmenters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LinderaMode {
+    /// The most likely words of the dictionary, compound words are kept whole.
+    Normal,
+    /// The long words, like compound words, are decomposed by penalizing their cost.
+    Decompose(LinderaPenalty),
+}
+
+impl Default for LinderaMode {
+    fn default() -> Self {
+        LinderaMode::Decompose(LinderaPenalty::default())
+    }
+}
+
+/// Penalties added to the cost of the long words in [`LinderaMode::Decompose`].
+///
+/// A word longer than a threshold has the corresponding penalty added to its cost
+/// for each character beyond the threshold.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LinderaPenalty {
+    /// Length beyond which a word only made of Kanji is penalized.
+    pub kanji_length_threshold: usize,
+    /// Penalty of each Kanji beyond the threshold.
+    pub kanji_length_penalty: i32,
+    /// Length beyond which the other words are penalized.
+    pub other_length_threshold: usize,
+    /// Penalty of each character beyond the threshold.
+    pub other_length_penalty: i32,
+}
+
+impl Default for LinderaPenalty {
+    fn default() -> Self {
+        let penalty = Penalty::default();
+        Self {
+            kanji_length_threshold: penalty.kanji_penalty_length_threshold,
+            kanji_length_penalty: penalty.kanji_penalty_length_penalty,
+            other_length_threshold: penalty.other_penalty_length_threshold,
+            other_length_penalty: penalty.other_penalty_length_penalty,
+        }
+    }
+}
+
+impl From<LinderaMode> for Mode {
+    fn from(mode: LinderaMode) -> Self {
+        match mode {
+            LinderaMode::Normal => Mode::Normal,
+            LinderaMode::Decompose(penalty) => Mode::Decompose(Penalty {
+                kanji_penalty_length_threshold: penalty.kanji_length_threshold,
+                kanji_penalty_length_penalty: penalty.kanji_length_penalty,
+                other_penalty_length_threshold: penalty.other_length_threshold,
+                other_penalty_length_penalty: penalty.other_length_penalty,
+            }),
+        }
+    }
+}
+
+/// Loads the dictionary of the given kind and builds the default lindera tokenizer using it.
+pub(crate) fn load_tokenizer(kind: DictionaryKind) -> Tokenizer {
+    let dictionary = load_dictionary_from_kind(kind.clone()).unwrap();
+    build_tokenizer(kind, dictionary, LinderaMode::default(), None).unwrap()
+}
+
+/// Builds a lindera tokenizer using the given dictionary of the given kind,
+/// extended with the words of the user dictionary if any.
+///
+/// The user dictionary segmenters reuse the dictionary of the default tokenizer instead of loading it again.
+pub(crate) fn build_tokenizer(
+    kind: DictionaryKind,
+    dictionary: Dictionary,
+    mode: LinderaMode,
+    user_dictionary: Option<&Path>,
+) -> Result<Tokenizer, LinderaError> {
+    let user_dictionary =
+        user_dictionary.map(|path| load_user_dictionary_from_csv(kind, path)).transpose()?;
+    let segmenter = LinderaSegmenter::new(mode.into(), dictionary, user_dictionary);
+    Ok(Tokenizer::new(segmenter))
+}
+
+/// Position of the base form and of the reading in the details of the words of a lindera dictionary,
+/// the part of speech is always the first detail.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct DetailsLayout {
+    pub base_form: Option<usize>,
+    pub reading: Option<usize>,
+}
+
+pub(crate) fn segment<'o>(
+    tokenizer: &Tokenizer,
+    to_segment: &'o str,
+) -> Box<dyn Iterator<Item = &'o str> + 'o> {
+    let tokens = tokenizer.tokenize(to_segment).unwrap();
+
+    let result: Vec<&'o str> = tokens
+        .into_iter()
+        .map(|token| {
+            let start = token.byte_start;
+            let end = token.byte_end;
+            &to_segment[start..end]
+        })
+        .collect();
+
+    Box::new(result.into_iter())
+}
+
+pub(crate) fn segment_with_metadata<'o>(
+    tokenizer: &Tokenizer,
+    layout: DetailsLayout,
+    to_segment: &'o str,
+) -> Box<dyn Iterator<Item = (&'o str, Option<TokenMetadata>)> + 'o> {
+    let tokens = tokenizer.tokenize(to_segment).unwrap();
+
+    let result: Vec<_> = tokens
+        .into_iter()
+        .map(|mut token| {
+            let segment = &to_segment[token.byte_start..token.byte_end];
+            let details = token.details();
+            // the unknown words only have a single `UNK` detail.
+            let metadata = (details.len() > 1).then(|| {
+                let detail = |index: Option<usize>| {
+                    index
+                        .and_then(|index| details.get(index))
+                        .filter(|detail| **detail != "*")
+                        .map(|detail| detail.to_string())
+                };
+                TokenMetadata {
+                    part_of_speech: detail(Some(0)),
+                    base_form: detail(layout.base_form),
+                    reading: detail(layout.reading),
+                }
+            });
+            (segment, metadata)
+        })
+        .collect();
+
+    Box::new(result.into_iter())
+}
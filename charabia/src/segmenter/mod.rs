@@ -1,32 +1,48 @@
 use std::borrow::Cow;
-use std::collections::HashMap;
-use std::sync::LazyLock;
+use std::cmp::Ordering;
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+use std::sync::{Arc, LazyLock};
 
 use aho_corasick::{AhoCorasick, FindIter, MatchKind};
 pub use arabic::ArabicSegmenter;
 #[cfg(feature = "chinese-segmentation")]
-pub use chinese::ChineseSegmenter;
+pub use chinese::{
+    ChineseSegmentationMode, ChineseSegmenter, ChineseUserDictSegmenter, ChineseUserWord,
+};
+#[cfg(feature = "devanagari")]
+pub use devanagari::DevanagariSegmenter;
 use either::Either;
 #[cfg(feature = "german-segmentation")]
 pub use german::GermanSegmenter;
 #[cfg(feature = "japanese")]
-pub use japanese::JapaneseSegmenter;
+pub use japanese::{JapaneseSegmenter, JapaneseUserDictSegmenter};
 #[cfg(feature = "khmer")]
 pub use khmer::KhmerSegmenter;
 #[cfg(feature = "korean")]
-pub use korean::KoreanSegmenter;
+pub use korean::{KoreanSegmenter, KoreanUserDictSegmenter};
+#[cfg(feature = "lao")]
+pub use lao::LaoSegmenter;
 pub use latin::LatinSegmenter;
+#[cfg(any(feature = "japanese", feature = "korean"))]
+pub use lindera_config::{LinderaMode, LinderaPenalty};
+#[cfg(feature = "myanmar")]
+pub use myanmar::MyanmarSegmenter;
 use slice_group_by::StrGroupBy;
 #[cfg(feature = "thai")]
 pub use thai::ThaiSegmenter;
 
-use crate::detection::{Detect, Language, Script, StrDetection};
+use crate::detection::{
+    ChineseVariant, Detect, Language, LanguageDetector, Script, StrDetection, WhatlangDetector,
+};
 use crate::separators::DEFAULT_SEPARATORS;
-use crate::token::Token;
+use crate::token::{Token, TokenMetadata};
 
 mod arabic;
 #[cfg(feature = "chinese-segmentation")]
 mod chinese;
+#[cfg(feature = "devanagari")]
+mod devanagari;
 #[cfg(feature = "german-segmentation")]
 mod german;
 #[cfg(feature = "japanese")]
@@ -35,13 +51,19 @@ mod japanese;
 mod khmer;
 #[cfg(feature = "korean")]
 mod korean;
+#[cfg(feature = "lao")]
+mod lao;
 mod latin;
+#[cfg(any(feature = "japanese", feature = "korean"))]
+mod lindera_config;
+#[cfg(feature = "myanmar")]
+mod myanmar;
 #[cfg(feature = "thai")]
 mod thai;
-#[cfg(any(feature = "thai", feature = "khmer"))]
+#[cfg(any(feature = "thai", feature = "khmer", feature = "lao", feature = "myanmar"))]
 mod utils;
 
-pub type SegmenterMap = HashMap<(Script, Option<Language>), Box<dyn Segmenter>>;
+pub type SegmenterMap = HashMap<(Script, Option<Language>), Arc<dyn Segmenter>>;
 
 /// List of used [`Segmenter`]s linked to their corresponding [`Script`] and [`Language`].
 ///
@@ -54,40 +76,164 @@ pub type SegmenterMap = HashMap<(Script, Option<Language>), Box<dyn Segmenter>>;
 /// A segmenter assigned to `Language::Other` is considered as the default `Segmenter` for any `Language` that uses the assigned `Script`.
 /// For example, [`LatinSegmenter`] is assigned to `(Script::Latin, Language::Other)`,
 /// meaning that `LatinSegmenter` is the default `Segmenter` for any `Language` that uses `Latin` `Script`.
-pub static SEGMENTERS: LazyLock<SegmenterMap> = LazyLock::new(|| {
+///
+/// This list is the one used by default, see [`SegmenterRegistry`] to use a custom list.
+pub static SEGMENTERS: LazyLock<SegmenterRegistry> = LazyLock::new(|| {
     vec![
         // latin segmenter
-        ((Script::Latin, None), Box::new(LatinSegmenter) as Box<dyn Segmenter>),
+        ((Script::Latin, None), Arc::new(LatinSegmenter) as Arc<dyn Segmenter>),
         #[cfg(feature = "swedish-recomposition")]
-        ((Script::Latin, Some(Language::Swe)), Box::new(LatinSegmenter) as Box<dyn Segmenter>),
+        ((Script::Latin, Some(Language::Swe)), Arc::new(LatinSegmenter) as Arc<dyn Segmenter>),
         // chinese segmenter
         #[cfg(feature = "chinese-segmentation")]
-        ((Script::Cj, Some(Language::Cmn)), Box::new(ChineseSegmenter) as Box<dyn Segmenter>),
+        ((Script::Cj, Some(Language::Cmn)), Arc::new(ChineseSegmenter) as Arc<dyn Segmenter>),
         #[cfg(feature = "chinese-segmentation")]
-        ((Script::Cj, Some(Language::Zho)), Box::new(ChineseSegmenter) as Box<dyn Segmenter>),
+        ((Script::Cj, Some(Language::Zho)), Arc::new(ChineseSegmenter) as Arc<dyn Segmenter>),
         // japanese segmenter
         #[cfg(feature = "japanese")]
-        ((Script::Cj, Some(Language::Jpn)), Box::new(JapaneseSegmenter) as Box<dyn Segmenter>),
+        ((Script::Cj, Some(Language::Jpn)), Arc::new(JapaneseSegmenter) as Arc<dyn Segmenter>),
         // korean segmenter
         #[cfg(feature = "korean")]
-        ((Script::Hangul, Some(Language::Kor)), Box::new(KoreanSegmenter) as Box<dyn Segmenter>),
+        ((Script::Hangul, Some(Language::Kor)), Arc::new(KoreanSegmenter) as Arc<dyn Segmenter>),
         // thai segmenter
         #[cfg(feature = "thai")]
-        ((Script::Thai, Some(Language::Tha)), Box::new(ThaiSegmenter) as Box<dyn Segmenter>),
+        ((Script::Thai, Some(Language::Tha)), Arc::new(ThaiSegmenter) as Arc<dyn Segmenter>),
         #[cfg(feature = "khmer")]
-        ((Script::Khmer, Some(Language::Khm)), Box::new(KhmerSegmenter) as Box<dyn Segmenter>),
+        ((Script::Khmer, Some(Language::Khm)), Arc::new(KhmerSegmenter) as Arc<dyn Segmenter>),
+        // lao segmenter
+        #[cfg(feature = "lao")]
+        ((Script::Lao, None), Arc::new(LaoSegmenter) as Arc<dyn Segmenter>),
+        // myanmar segmenter
+        #[cfg(feature = "myanmar")]
+        ((Script::Myanmar, Some(Language::Mya)), Arc::new(MyanmarSegmenter) as Arc<dyn Segmenter>),
         // arabic segmenter
-        ((Script::Arabic, Some(Language::Ara)), Box::new(ArabicSegmenter) as Box<dyn Segmenter>),
+        ((Script::Arabic, Some(Language::Ara)), Arc::new(ArabicSegmenter) as Arc<dyn Segmenter>),
         // persian segmenter
-        ((Script::Arabic, Some(Language::Pes)), Box::new(ArabicSegmenter) as Box<dyn Segmenter>),
+        ((Script::Arabic, Some(Language::Pes)), Arc::new(ArabicSegmenter) as Arc<dyn Segmenter>),
         // german segmenter
         #[cfg(feature = "german-segmentation")]
-        ((Script::Latin, Some(Language::Deu)), Box::new(GermanSegmenter) as Box<dyn Segmenter>),
+        ((Script::Latin, Some(Language::Deu)), Arc::new(GermanSegmenter) as Arc<dyn Segmenter>),
+        // devanagari segmenter
+        #[cfg(feature = "devanagari")]
+        ((Script::Devanagari, None), Arc::new(DevanagariSegmenter) as Arc<dyn Segmenter>),
     ]
     .into_iter()
     .collect()
 });
 
+/// Runtime-configurable list of [`Segmenter`]s linked to their corresponding [`Script`] and [`Language`].
+///
+/// A registry follows the same lookup rules as [`SEGMENTERS`],
+/// it can start from the default segmenters and then be customized before being given to [`TokenizerBuilder::segmenters`].
+///
+/// [`TokenizerBuilder::segmenters`]: crate::TokenizerBuilder::segmenters
+///
+/// # Example
+///
+/// ```
+/// use charabia::segmenter::{Segmenter, SegmenterRegistry};
+/// use charabia::{Script, TokenizerBuilder};
+///
+/// // a segmenter splitting the text in characters.
+/// struct CharSegmenter;
+///
+/// impl Segmenter for CharSegmenter {
+///     fn segment_str<'o>(&self, s: &'o str) -> Box<dyn Iterator<Item = &'o str> + 'o> {
+///         Box::new(s.char_indices().map(move |(i, c)| &s[i..i + c.len_utf8()]))
+///     }
+/// }
+///
+/// // start from the default segmenters and override the Latin one.
+/// let mut segmenters = SegmenterRegistry::new();
+/// segmenters.insert(Script::Latin, None, CharSegmenter);
+///
+/// let mut builder = TokenizerBuilder::default();
+/// builder.segmenters(segmenters);
+/// let tokenizer = builder.build();
+///
+/// let output: Vec<_> = tokenizer.segment_str("fox jumps").collect();
+/// assert_eq!(output, ["f", "o", "x", " ", "j", "u", "m", "p", "s"]);
+/// ```
+#[derive(Clone)]
+pub struct SegmenterRegistry {
+    segmenters: SegmenterMap,
+}
+
+impl SegmenterRegistry {
+    /// Creates a registry containing the default segmenters listed in [`SEGMENTERS`].
+    pub fn new() -> Self {
+        SEGMENTERS.clone()
+    }
+
+    /// Creates a registry without any segmenter,
+    /// every text will be segmented by the [`DEFAULT_SEGMENTER`].
+    pub fn empty() -> Self {
+        Self { segmenters: HashMap::new() }
+    }
+
+    /// Assigns a [`Segmenter`] to a [`Script`] and an optional [`Language`],
+    /// returning the previously assigned segmenter if any.
+    ///
+    /// A segmenter assigned to a `None` language is the default `Segmenter` for any `Language` that uses the assigned `Script`.
+    pub fn insert(
+        &mut self,
+        script: Script,
+        language: Option<Language>,
+        segmenter: impl Segmenter + 'static,
+    ) -> Option<Arc<dyn Segmenter>> {
+        self.segmenters.insert((script, language), Arc::new(segmenter))
+    }
+
+    /// Removes the [`Segmenter`] assigned to a [`Script`] and an optional [`Language`],
+    /// returning it if any.
+    pub fn remove(
+        &mut self,
+        script: Script,
+        language: Option<Language>,
+    ) -> Option<Arc<dyn Segmenter>> {
+        self.segmenters.remove(&(script, language))
+    }
+
+    /// Returns the [`Segmenter`] assigned to a [`Script`] and an optional [`Language`] if any.
+    pub fn get(&self, script: Script, language: Option<Language>) -> Option<&dyn Segmenter> {
+        self.segmenters.get(&(script, language)).map(|segmenter| &**segmenter)
+    }
+
+    /// Returns true if a [`Segmenter`] is assigned to the [`Script`] and the optional [`Language`].
+    pub fn contains(&self, script: Script, language: Option<Language>) -> bool {
+        self.segmenters.contains_key(&(script, language))
+    }
+
+    /// Returns an iterator over the registered [`Script`] and [`Language`] pairs.
+    pub fn keys(&self) -> impl Iterator<Item = &(Script, Option<Language>)> {
+        self.segmenters.keys()
+    }
+
+    fn iter(&self) -> impl Iterator<Item = (&(Script, Option<Language>), &dyn Segmenter)> {
+        self.segmenters.iter().map(|(key, segmenter)| (key, &**segmenter))
+    }
+}
+
+impl Default for SegmenterRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for SegmenterRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.segmenters.keys()).finish()
+    }
+}
+
+impl FromIterator<((Script, Option<Language>), Arc<dyn Segmenter>)> for SegmenterRegistry {
+    fn from_iter<I: IntoIterator<Item = ((Script, Option<Language>), Arc<dyn Segmenter>)>>(
+        iter: I,
+    ) -> Self {
+        Self { segmenters: iter.into_iter().collect() }
+    }
+}
+
 /// Picked [`Segmenter`] when no segmenter is specialized to the detected [`Script`].
 pub static DEFAULT_SEGMENTER: LazyLock<Box<dyn Segmenter>> =
     LazyLock::new(|| Box::new(LatinSegmenter));
@@ -97,53 +243,173 @@ pub static DEFAULT_SEPARATOR_AHO: LazyLock<AhoCorasick> = LazyLock::new(|| {
 });
 
 /// Iterator over segmented [`Token`]s.
+///
+/// The tokens of a segmenter returning overlapping words, like the full Chinese segmentation,
+/// are positioned on the boundaries of the words: a group of overlapping tokens takes one position
+/// per word boundary it contains, a token is incremented by the boundaries separating it from the previous token,
+/// and its `position_length` is the number of boundaries it spans.
+/// A token that doesn't overlap any other spans a single position, like the tokens of the other segmenters.
 pub struct SegmentedTokenIter<'o, 'aho, 'lang> {
     inner: SegmentedStrIter<'o, 'aho, 'lang>,
     char_index: usize,
     byte_index: usize,
+    /// The positioned tokens of the current group of overlapping tokens.
+    group: VecDeque<Token<'o>>,
+    /// The token following the current group, read ahead to know where the group ends.
+    peeked: Option<Token<'o>>,
 }
 
-impl<'o> Iterator for SegmentedTokenIter<'o, '_, '_> {
-    type Item = Token<'o>;
+impl<'o> SegmentedTokenIter<'o, '_, '_> {
+    /// Returns the next segmented token, without its position.
+    fn next_unpositioned(&mut self) -> Option<Token<'o>> {
+        let (lemma, metadata) = self.inner.next_segment()?;
+        let original = self.inner.original;
 
-    fn next(&mut self) -> Option<Self::Item> {
-        let lemma = self.inner.next()?;
-        let char_start = self.char_index;
-        let byte_start = self.byte_index;
+        // a segmenter returning overlapping words returns words starting before the end of the previous ones.
+        let byte_start = if self.inner.overlapping {
+            subslice_offset(original, lemma).unwrap_or(self.byte_index)
+        } else {
+            self.byte_index
+        };
+        let char_start = match byte_start.cmp(&self.byte_index) {
+            Ordering::Less => {
+                self.char_index - original[byte_start..self.byte_index].chars().count()
+            }
+            Ordering::Equal => self.char_index,
+            Ordering::Greater => {
+                self.char_index + original[self.byte_index..byte_start].chars().count()
+            }
+        };
+        let byte_end = byte_start + lemma.len();
+        let char_end = char_start + lemma.chars().count();
 
-        self.char_index += lemma.chars().count();
-        self.byte_index += lemma.len();
+        if byte_end > self.byte_index {
+            self.char_index = char_end;
+            self.byte_index = byte_end;
+        }
 
         Some(Token {
             lemma: Cow::Borrowed(lemma),
             script: self.inner.script,
             language: self.inner.language,
+            language_hinted: self.inner.language_hinted,
+            chinese_variant: self.inner.chinese_variant,
             char_start,
-            char_end: self.char_index,
+            char_end,
             byte_start,
-            byte_end: self.byte_index,
+            byte_end,
+            metadata: metadata.map(Box::new),
             ..Default::default()
         })
     }
 }
 
+impl<'o> Iterator for SegmentedTokenIter<'o, '_, '_> {
+    type Item = Token<'o>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(token) = self.group.pop_front() {
+            return Some(token);
+        }
+
+        let token = self.peeked.take().or_else(|| self.next_unpositioned())?;
+        // only the tokens of a segmenter returning overlapping words are read ahead.
+        if !self.inner.overlapping {
+            return Some(token);
+        }
+
+        let mut end = token.char_end;
+        self.group.push_back(token);
+        while let Some(next) = self.next_unpositioned() {
+            if next.char_start >= end {
+                self.peeked = Some(next);
+                break;
+            }
+            end = end.max(next.char_end);
+            self.group.push_back(next);
+        }
+
+        let following_increment = position_group(self.group.make_contiguous());
+        if let Some(peeked) = &mut self.peeked {
+            peeked.position_increment = following_increment;
+        }
+
+        self.group.pop_front()
+    }
+}
+
+/// Positions a group of overlapping tokens on the boundaries of its tokens,
+/// returning the increment of the token following the group.
+fn position_group(group: &mut [Token]) -> usize {
+    let mut boundaries: Vec<_> = group.iter().flat_map(|t| [t.char_start, t.char_end]).collect();
+    boundaries.sort_unstable();
+    boundaries.dedup();
+    let rank = |offset| boundaries.partition_point(|boundary| *boundary < offset);
+
+    let mut last_start = None;
+    for token in group.iter_mut() {
+        let start = rank(token.char_start);
+        if let Some(last_start) = last_start {
+            token.position_increment = start.saturating_sub(last_start);
+        }
+        token.position_length = rank(token.char_end) - start;
+        last_start = Some(start);
+    }
+
+    // the token following the group starts at the last boundary of the group.
+    boundaries.len().saturating_sub(1) - last_start.unwrap_or(0)
+}
+
+/// Returns the byte offset of `sub` in `original` if `sub` is a subslice of `original`.
+fn subslice_offset(original: &str, sub: &str) -> Option<usize> {
+    let offset = (sub.as_ptr() as usize).checked_sub(original.as_ptr() as usize)?;
+    (offset + sub.len() <= original.len()).then_some(offset)
+}
+
 impl<'o, 'aho, 'lang> From<SegmentedStrIter<'o, 'aho, 'lang>>
     for SegmentedTokenIter<'o, 'aho, 'lang>
 {
     fn from(segmented_str_iter: SegmentedStrIter<'o, 'aho, 'lang>) -> Self {
-        Self { inner: segmented_str_iter, char_index: 0, byte_index: 0 }
+        Self {
+            inner: segmented_str_iter,
+            char_index: 0,
+            byte_index: 0,
+            group: VecDeque::new(),
+            peeked: None,
+        }
     }
 }
 
 pub struct SegmentedStrIter<'o, 'aho, 'lang> {
     inner: Box<dyn Iterator<Item = &'o str> + 'o>,
-    current: Box<dyn Iterator<Item = &'o str> + 'o>,
+    current: Box<dyn Iterator<Item = (&'o str, Option<TokenMetadata>)> + 'o>,
+    /// `true` if the metadata given by the segmenters are kept, see [`SegmentedStrIter::with_metadata`].
+    with_metadata: bool,
+    /// `true` if the language is detected even when the segmenter doesn't depend on it,
+    /// see [`SegmentedStrIter::with_language_detection`].
+    language_detection: bool,
     aho_iter: Option<AhoSegmentedStrIter<'o, 'aho>>,
-    segmenter: &'static dyn Segmenter,
+    segmenter: &'aho dyn Segmenter,
+    /// `true` if the current segmenter returns overlapping words, see [`Segmenter::overlapping_segments`].
+    overlapping: bool,
+    segmenters: &'aho SegmenterRegistry,
     aho: Option<&'aho AhoCorasick>,
     allow_list: Option<&'lang [Language]>,
+    language_detector: &'aho dyn LanguageDetector,
+    min_language_confidence: f64,
+    original: &'o str,
+    document_languages: Option<DocumentLanguages>,
+    language_hints: Option<&'lang HashMap<Script, Language>>,
     script: Script,
     language: Option<Language>,
+    language_hinted: bool,
+    chinese_variant: Option<ChineseVariant>,
+}
+
+/// Languages detected on the whole document for each script, see [`SegmentedStrIter::with_document_language_detection`].
+struct DocumentLanguages {
+    min_run_len: usize,
+    languages: Vec<(Script, Option<Language>)>,
 }
 
 impl<'o, 'aho, 'lang> SegmentedStrIter<'o, 'aho, 'lang> {
@@ -152,59 +418,189 @@ impl<'o, 'aho, 'lang> SegmentedStrIter<'o, 'aho, 'lang> {
         aho: Option<&'aho AhoCorasick>,
         allow_list: Option<&'lang [Language]>,
     ) -> Self {
-        let mut current_script = Script::Other;
-        let mut group_id = 0;
-        let inner = original.linear_group_by_key(move |c| {
-            let script = Script::from(c);
-            if script != Script::Other && script != current_script {
-                // if both previous and current scripts are differents than Script::Other,
-                // split into a new script group.
-                if current_script != Script::Other {
-                    group_id += 1;
-                }
-                current_script = script
-            }
-            group_id
-        });
+        Self::with_segmenters(original, aho, allow_list, &SEGMENTERS)
+    }
 
+    /// Same as [`SegmentedStrIter::new`] but picks the [`Segmenter`]s in the provided [`SegmenterRegistry`]
+    /// instead of the default [`SEGMENTERS`].
+    pub fn with_segmenters(
+        original: &'o str,
+        aho: Option<&'aho AhoCorasick>,
+        allow_list: Option<&'lang [Language]>,
+        segmenters: &'aho SegmenterRegistry,
+    ) -> Self {
         Self {
-            inner: Box::new(inner),
+            inner: Box::new(script_runs(original)),
             current: Box::new(None.into_iter()),
+            with_metadata: false,
+            language_detection: false,
             aho_iter: None,
             segmenter: &*DEFAULT_SEGMENTER,
+            overlapping: false,
+            segmenters,
             aho,
             allow_list,
+            language_detector: &WhatlangDetector,
+            min_language_confidence: 0.0,
+            original,
+            document_languages: None,
+            language_hints: None,
             script: Script::Other,
             language: None,
+            language_hinted: false,
+            chinese_variant: None,
         }
     }
+
+    /// Detect the languages with the given [`LanguageDetector`] instead of the default [`WhatlangDetector`].
+    pub fn with_language_detector(mut self, language_detector: &'aho dyn LanguageDetector) -> Self {
+        self.language_detector = language_detector;
+        self
+    }
+
+    /// Detect the language of each text even when a single [`Segmenter`] is available for its script,
+    /// by default the language is only detected to pick the segmenter.
+    pub fn with_language_detection(mut self, language_detection: bool) -> Self {
+        self.language_detection = language_detection;
+        self
+    }
+
+    /// Ignore the detected languages having a confidence lower than `min_confidence`,
+    /// the default [`Segmenter`] of the script is used instead.
+    pub fn with_min_language_confidence(mut self, min_confidence: f64) -> Self {
+        self.min_language_confidence = min_confidence;
+        self
+    }
+
+    /// Keep the [`TokenMetadata`] given by the segmenters, like the part of speech of the Japanese words,
+    /// only the [`Token`]s segmented from this iterator use them.
+    pub fn with_metadata(mut self, with_metadata: bool) -> Self {
+        self.with_metadata = with_metadata;
+        self
+    }
+
+    /// Detect the language of each script once on the whole text and use it for every run of this script,
+    /// a run containing at least `min_run_len` characters can override it if its language is reliably detected.
+    pub fn with_document_language_detection(mut self, min_run_len: usize) -> Self {
+        self.document_languages = Some(DocumentLanguages { min_run_len, languages: Vec::new() });
+        self
+    }
+
+    /// Force the [`Language`] of the runs of the given [`Script`]s instead of detecting it.
+    pub fn with_language_hints(mut self, language_hints: &'lang HashMap<Script, Language>) -> Self {
+        self.language_hints = Some(language_hints);
+        self
+    }
+
+    /// Returns the language detected on all the runs of the given script, computing it only once.
+    fn document_language(&mut self, script: Script) -> Option<(Language, usize)> {
+        let document = self.document_languages.as_mut()?;
+        let language = match document.languages.iter().find(|(s, _)| *s == script) {
+            Some((_, language)) => *language,
+            None => {
+                let runs: Vec<_> = script_runs(self.original)
+                    .filter(|run| run.detect(None).script() == script)
+                    .collect();
+                let text = runs.join(" ");
+                let language = text
+                    .as_str()
+                    .detect(self.allow_list)
+                    .with_detector(self.language_detector)
+                    .with_min_confidence(self.min_language_confidence)
+                    .language();
+                document.languages.push((script, language));
+                language
+            }
+        };
+
+        language.map(|language| (language, document.min_run_len))
+    }
+}
+
+/// Splits the text in runs of the same script,
+/// the characters without script are kept in the current run.
+fn script_runs(original: &str) -> impl Iterator<Item = &str> {
+    let mut current_script = Script::Other;
+    let mut group_id = 0;
+    original.linear_group_by_key(move |c| {
+        let script = Script::from(c);
+        if script != Script::Other && script != current_script {
+            // if both previous and current scripts are differents than Script::Other,
+            // split into a new script group.
+            if current_script != Script::Other {
+                group_id += 1;
+            }
+            current_script = script
+        }
+        group_id
+    })
 }
 
 impl<'o> Iterator for SegmentedStrIter<'o, '_, '_> {
     type Item = &'o str;
 
     fn next(&mut self) -> Option<Self::Item> {
+        self.next_segment().map(|(s, _)| s)
+    }
+}
+
+impl<'o> SegmentedStrIter<'o, '_, '_> {
+    /// Returns the next segment with the metadata given by the segmenter, if any.
+    fn next_segment(&mut self) -> Option<(&'o str, Option<TokenMetadata>)> {
         match self.current.next() {
-            Some(s) => Some(s),
+            Some(segment) => Some(segment),
             None => match self.aho_iter.as_mut().and_then(|aho_iter| aho_iter.next()) {
-                Some((s, MatchType::Match)) => Some(s),
+                Some((s, MatchType::Match)) => Some((s, None)),
                 Some((s, MatchType::Interleave)) => {
-                    self.current = self.segmenter.segment_str(s);
+                    self.current = if self.with_metadata {
+                        self.segmenter.segment_str_with_metadata(s)
+                    } else {
+                        Box::new(self.segmenter.segment_str(s).map(|s| (s, None)))
+                    };
 
-                    self.next()
+                    self.next_segment()
                 }
                 None => {
                     let text = self.inner.next()?;
-                    let mut detector = text.detect(self.allow_list);
-                    self.segmenter = segmenter(&mut detector);
+                    let mut detector = text
+                        .detect(self.allow_list)
+                        .with_detector(self.language_detector)
+                        .with_min_confidence(self.min_language_confidence);
+                    let hint = self.language_hints.and_then(|hints| hints.get(&detector.script()));
+                    self.language_hinted = hint.is_some();
+                    if let Some(language) = hint {
+                        detector.language = Some(*language);
+                    } else if self.document_languages.is_some() {
+                        let script = detector.script();
+                        if let Some((language, min_run_len)) = self.document_language(script) {
+                            detector = detector.with_document_language(language, min_run_len);
+                        }
+                    }
+                    if self.language_detection {
+                        detector.language();
+                    }
+                    self.segmenter = segmenter(&mut detector, self.segmenters);
+                    self.overlapping = self.segmenter.overlapping_segments();
                     self.script = detector.script();
+                    // the variant is only detected on the Chinese runs, not on the Japanese ones.
+                    self.chinese_variant = match self.script {
+                        Script::Cj
+                            if matches!(
+                                detector.language(),
+                                Some(Language::Cmn | Language::Zho)
+                            ) =>
+                        {
+                            ChineseVariant::detect(text)
+                        }
+                        _ => None,
+                    };
                     self.language = detector.language;
                     self.aho_iter = Some(AhoSegmentedStrIter::new(
                         text,
                         self.aho.unwrap_or(&DEFAULT_SEPARATOR_AHO),
                     ));
 
-                    self.next()
+                    self.next_segment()
                 }
             },
         }
@@ -277,14 +673,17 @@ enum MatchType {
 /// if no Script is detected or no segmenter corresponds to the Script,
 /// the function try to get the default segmenter in the map;
 /// if no default segmenter exists in the map return the library DEFAULT_SEGMENTER.
-fn segmenter<'b>(detector: &mut StrDetection) -> &'b dyn Segmenter {
+fn segmenter<'b>(
+    detector: &mut StrDetection,
+    segmenters: &'b SegmenterRegistry,
+) -> &'b dyn Segmenter {
     let detected_script = detector.script();
     let mut filtered_segmenters =
-        SEGMENTERS.iter().filter(|((script, _), _)| *script == detected_script);
+        segmenters.iter().filter(|((script, _), _)| *script == detected_script);
     match (filtered_segmenters.next(), filtered_segmenters.next()) {
         // no specialized segmenter found for this script,
         // choose the default one.
-        (None, None) => &*DEFAULT_SEGMENTER,
+        (None, None) => &**DEFAULT_SEGMENTER,
         // Only one specialized segmenter found,
         // we don't need to detect the Language.
         (Some((_, segmenter)), None) => segmenter,
@@ -292,10 +691,10 @@ fn segmenter<'b>(detector: &mut StrDetection) -> &'b dyn Segmenter {
         // we have to detect the language to get the good one.
         _ => {
             let detected_language = detector.language();
-            SEGMENTERS
-                .get(&(detected_script, detected_language))
-                .or_else(|| SEGMENTERS.get(&(detected_script, None)))
-                .unwrap_or(&DEFAULT_SEGMENTER)
+            segmenters
+                .get(detected_script, detected_language)
+                .or_else(|| segmenters.get(detected_script, None))
+                .unwrap_or(&**DEFAULT_SEGMENTER)
         }
     }
 }
@@ -305,6 +704,11 @@ fn segmenter<'b>(detector: &mut StrDetection) -> &'b dyn Segmenter {
 pub struct SegmenterOption<'tb> {
     pub aho: Option<AhoCorasick>,
     pub allow_list: Option<&'tb [Language]>,
+    pub language_detector: Option<&'tb dyn LanguageDetector>,
+    pub min_language_confidence: f64,
+    pub document_language_min_run_len: Option<usize>,
+    pub segmenters: SegmenterRegistry,
+    pub with_metadata: bool,
 }
 
 /// Trait defining a segmenter.
@@ -313,12 +717,42 @@ pub struct SegmenterOption<'tb> {
 pub trait Segmenter: Sync + Send {
     /// Segments the provided text creating an Iterator over `&str`.
     fn segment_str<'o>(&self, s: &'o str) -> Box<dyn Iterator<Item = &'o str> + 'o>;
+
+    /// Segments the provided text creating an Iterator over `&str` and their [`TokenMetadata`].
+    ///
+    /// By default, the segments don't have any metadata,
+    /// a segmenter knowing the part of speech, the base form or the reading of the segments should override it.
+    fn segment_str_with_metadata<'o>(
+        &self,
+        s: &'o str,
+    ) -> Box<dyn Iterator<Item = (&'o str, Option<TokenMetadata>)> + 'o> {
+        Box::new(self.segment_str(s).map(|s| (s, None)))
+    }
+
+    /// Returns `true` if the segments can overlap each other, like the words of the full Chinese segmentation.
+    ///
+    /// By default, the segments follow each other,
+    /// the tokens of a segmenter returning overlapping segments are positioned as described in [`SegmentedTokenIter`].
+    fn overlapping_segments(&self) -> bool {
+        false
+    }
 }
 
 impl Segmenter for Box<dyn Segmenter> {
     fn segment_str<'o>(&self, s: &'o str) -> Box<dyn Iterator<Item = &'o str> + 'o> {
         (**self).segment_str(s)
     }
+
+    fn segment_str_with_metadata<'o>(
+        &self,
+        s: &'o str,
+    ) -> Box<dyn Iterator<Item = (&'o str, Option<TokenMetadata>)> + 'o> {
+        (**self).segment_str_with_metadata(s)
+    }
+
+    fn overlapping_segments(&self) -> bool {
+        (**self).overlapping_segments()
+    }
 }
 
 /// Trait defining methods to segment a text.
@@ -407,12 +841,20 @@ impl<'o> Segment<'o> for &'o str {
 mod test {
     macro_rules! test_segmenter {
     ($segmenter:expr, $text:expr, $segmented:expr, $tokenized:expr, $script:expr, $language:expr) => {
+            test_segmenter!(@language $segmenter, $text, $segmented, $tokenized, $script, Some($language));
+    };
+    // for the scripts that don't have any detectable language.
+    ($segmenter:expr, $text:expr, $segmented:expr, $tokenized:expr, $script:expr) => {
+            test_segmenter!(@language $segmenter, $text, $segmented, $tokenized, $script, None);
+    };
+    (@language $segmenter:expr, $text:expr, $segmented:expr, $tokenized:expr, $script:expr, $language:expr) => {
             use aho_corasick::{AhoCorasick, MatchKind};
             use std::sync::LazyLock;
             use crate::{Token, Language, Script};
             use crate::segmenter::{Segment, AhoSegmentedStrIter, MatchType, DEFAULT_SEPARATOR_AHO};
             use super::*;
 
+            const LANGUAGE: Option<Language> = $language;
             const NUMBER_SEPARATOR: &[&str] = &[" "];
             const TEXT_NUMBER: &str = "123 -123 +123 12.3 -12.3 +12.3";
             const SEGMENTED_NUMBER: &[&str] =
@@ -440,7 +882,7 @@ help: the `segmented` text provided to `test_segmenter!` does not corresponds to
             #[test]
             fn text_lang_script_assignment() {
                 let Token {script, language, ..} = $text.segment().next().unwrap();
-                assert_eq!((script, language.unwrap_or($language)), ($script, $language), r#"
+                assert_eq!((script, language.or(LANGUAGE)), ($script, LANGUAGE), r#"
 Provided text is not detected as the expected Script or Language to be segmented by {}.
 
 help: The tokenizer Script/Language detector detected the wrong Script/Language for the `segmented` text, the provided text will probably be segmented by an other segmenter.
@@ -450,7 +892,7 @@ Check if the expected Script/Language corresponds to the detected Script/Languag
 
             #[test]
             fn segment() {
-                let segmented_text: Vec<_> = $text.segment_str_with_option(None, Some(&[$language])).collect();
+                let segmented_text: Vec<_> = $text.segment_str_with_option(None, LANGUAGE.as_ref().map(std::slice::from_ref)).collect();
                 assert_eq!(&segmented_text[..], $segmented, r#"
 Segmenter chosen by global segment() function, didn't segment the text as expected.
 
@@ -462,7 +904,7 @@ Check if the tested segmenter is assigned to the good Script/Language in `SEGMEN
             #[test]
             fn tokenize() {
                 let tokenizer = crate::TokenizerBuilder::default().into_tokenizer();
-                let tokens: Vec<_> = tokenizer.tokenize_with_allow_list($text, Some(&[$language])).collect();
+                let tokens: Vec<_> = tokenizer.tokenize_with_allow_list($text, LANGUAGE.as_ref().map(std::slice::from_ref)).collect();
                 let tokenized_text: Vec<_> = tokens.iter().map(|t| t.lemma()).collect();
 
                 assert_eq!(&tokenized_text[..], $tokenized, r#"
@@ -498,7 +940,7 @@ help: the `segmented` text provided to `test_segmenter!` does not corresponds to
                 let mut builder = crate::TokenizerBuilder::default();
                 builder.separators(NUMBER_SEPARATOR);
                 let tokenizer = builder.build();
-                let tokens: Vec<_> = tokenizer.tokenize_with_allow_list(TEXT_NUMBER, Some(&[$language])).collect();
+                let tokens: Vec<_> = tokenizer.tokenize_with_allow_list(TEXT_NUMBER, LANGUAGE.as_ref().map(std::slice::from_ref)).collect();
                 let tokenized_text: Vec<_> = tokens.iter().map(|t| t.lemma()).collect();
 
                 assert_eq!(&tokenized_text[..], TOKENIZED_NUMBER, r#"
@@ -512,4 +954,312 @@ Make sure that normalized text is valid or change the trigger condition of the n
         }
     }
     pub(crate) use test_segmenter;
+
+    use super::{LatinSegmenter, Segmenter, SegmenterRegistry};
+    use crate::{Language, LanguageDetector, Script, TokenMetadata, Tokenize, TokenizerBuilder};
+
+    struct CharSegmenter;
+
+    impl Segmenter for CharSegmenter {
+        fn segment_str<'o>(&self, s: &'o str) -> Box<dyn Iterator<Item = &'o str> + 'o> {
+            Box::new(s.char_indices().map(move |(i, c)| &s[i..i + c.len_utf8()]))
+        }
+    }
+
+    /// Segmenter returning each character followed by the bigram starting with it.
+    struct OverlappingSegmenter;
+
+    impl Segmenter for OverlappingSegmenter {
+        fn segment_str<'o>(&self, s: &'o str) -> Box<dyn Iterator<Item = &'o str> + 'o> {
+            let bounds: Vec<_> = s.char_indices().map(|(i, _)| i).chain([s.len()]).collect();
+            let segments: Vec<_> = bounds
+                .windows(2)
+                .enumerate()
+                .flat_map(|(i, window)| {
+                    let bigram = bounds.get(i + 2).map(|end| &s[window[0]..*end]);
+                    std::iter::once(&s[window[0]..window[1]]).chain(bigram)
+                })
+                .collect();
+            Box::new(segments.into_iter())
+        }
+
+        fn overlapping_segments(&self) -> bool {
+            true
+        }
+    }
+
+    #[test]
+    fn overlapping_segments() {
+        let mut segmenters = SegmenterRegistry::empty();
+        segmenters.insert(Script::Latin, None, OverlappingSegmenter);
+        let mut builder = TokenizerBuilder::default();
+        builder.segmenters(segmenters);
+        let tokenizer = builder.build();
+
+        let tokens: Vec<_> = tokenizer
+            .segment("aéb")
+            .map(|t| {
+                (
+                    t.lemma().to_string(),
+                    t.char_start,
+                    t.byte_start,
+                    t.position_increment,
+                    t.position_length,
+                )
+            })
+            .collect();
+        assert_eq!(
+            tokens,
+            [
+                ("a".to_string(), 0, 0, 1, 1),
+                ("aé".to_string(), 0, 0, 0, 2),
+                ("é".to_string(), 1, 1, 1, 1),
+                ("éb".to_string(), 1, 1, 0, 2),
+                ("b".to_string(), 2, 3, 1, 1),
+            ]
+        );
+    }
+
+    /// Segmenter returning the first half of each word, the whole word and its second half.
+    struct HalvesSegmenter;
+
+    impl Segmenter for HalvesSegmenter {
+        fn segment_str<'o>(&self, s: &'o str) -> Box<dyn Iterator<Item = &'o str> + 'o> {
+            let half = s.char_indices().nth(s.chars().count() / 2).map_or(s.len(), |(i, _)| i);
+            match half {
+                0 => Box::new(std::iter::once(s)),
+                half => Box::new([&s[..half], s, &s[half..]].into_iter()),
+            }
+        }
+
+        fn overlapping_segments(&self) -> bool {
+            true
+        }
+    }
+
+    #[test]
+    fn overlapping_segments_phrase() {
+        let mut segmenters = SegmenterRegistry::empty();
+        segmenters.insert(Script::Latin, None, HalvesSegmenter);
+        let mut builder = TokenizerBuilder::default();
+        builder.segmenters(segmenters);
+        let tokenizer = builder.build();
+
+        let mut position = 0;
+        let tokens: Vec<_> = tokenizer
+            .segment("abcd ef")
+            .map(|t| {
+                position += t.position_increment;
+                (t.lemma().to_string(), position - 1, t.position_length)
+            })
+            .collect();
+        // the positions are the word boundaries, "ab" and "cd" span a single position.
+        assert_eq!(
+            tokens,
+            [
+                ("ab".to_string(), 0, 1),
+                ("abcd".to_string(), 0, 2),
+                ("cd".to_string(), 1, 1),
+                (" ".to_string(), 2, 1),
+                ("e".to_string(), 3, 1),
+                ("ef".to_string(), 3, 2),
+                ("f".to_string(), 4, 1),
+            ]
+        );
+
+        // the phrase "cd e" spans the end of the first group and the start of the second one.
+        let positions: Vec<_> =
+            ["cd", " ", "e"].map(|lemma| tokens.iter().find(|t| t.0 == lemma).unwrap().1).into();
+        assert_eq!(positions, [1, 2, 3]);
+        // the phrase "abcd ef" follows the alternatives.
+        let (_, abcd, abcd_length) = &tokens[1];
+        let (_, ef, _) = &tokens[5];
+        assert_eq!(abcd + abcd_length + 1, *ef);
+    }
+
+    #[test]
+    fn non_overlapping_segments_positions() {
+        // the default segmenters don't read ahead, each token takes the next position.
+        let tokenizer = TokenizerBuilder::default().into_tokenizer();
+        let tokens: Vec<_> = tokenizer
+            .segment("aéb c")
+            .map(|t| (t.char_start, t.byte_start, t.position_increment, t.position_length))
+            .collect();
+        assert_eq!(tokens, [(0, 0, 1, 1), (3, 4, 1, 1), (4, 5, 1, 1)]);
+    }
+
+    /// Segmenter giving the uppercased word as base form of each word.
+    struct MetadataSegmenter;
+
+    impl Segmenter for MetadataSegmenter {
+        fn segment_str<'o>(&self, s: &'o str) -> Box<dyn Iterator<Item = &'o str> + 'o> {
+            LatinSegmenter.segment_str(s)
+        }
+
+        fn segment_str_with_metadata<'o>(
+            &self,
+            s: &'o str,
+        ) -> Box<dyn Iterator<Item = (&'o str, Option<TokenMetadata>)> + 'o> {
+            Box::new(self.segment_str(s).map(|s| {
+                let base_form = Some(s.to_uppercase());
+                (s, Some(TokenMetadata { base_form, ..Default::default() }))
+            }))
+        }
+    }
+
+    #[test]
+    fn segmenter_metadata() {
+        let mut segmenters = SegmenterRegistry::empty();
+        segmenters.insert(Script::Latin, None, MetadataSegmenter);
+        let mut builder = TokenizerBuilder::default();
+        builder.segmenters(segmenters.clone());
+        let tokenizer = builder.build();
+        // the metadata are only kept on demand.
+        assert!(tokenizer.tokenize("Quick fox").all(|t| t.metadata.is_none()));
+
+        let mut builder = TokenizerBuilder::default();
+        builder.segmenters(segmenters).token_metadata(true);
+        let tokenizer = builder.build();
+
+        let base_forms: Vec<_> =
+            tokenizer.tokenize("Quick fox").map(|t| t.metadata.and_then(|m| m.base_form)).collect();
+        // the separators are not segmented by the segmenter.
+        let expected = [Some("QUICK"), None, Some("FOX")].map(|s| s.map(String::from));
+        assert_eq!(base_forms, expected);
+
+        // the default segmenters don't give any metadata.
+        assert!("Quick fox".tokenize().all(|t| t.metadata.is_none()));
+    }
+
+    #[test]
+    fn segmenter_registry_override() {
+        let mut segmenters = SegmenterRegistry::new();
+        let previous = segmenters.insert(Script::Latin, None, CharSegmenter);
+        assert!(previous.is_some());
+
+        let mut builder = TokenizerBuilder::default();
+        builder.segmenters(segmenters);
+        let tokenizer = builder.build();
+
+        let segmented: Vec<_> = tokenizer.segment_str("fox 42").collect();
+        assert_eq!(segmented, ["f", "o", "x", " ", "42"]);
+    }
+
+    #[test]
+    fn segmenter_registry_remove() {
+        let text = "السلام عليكم";
+
+        let tokenizer = TokenizerBuilder::default().into_tokenizer();
+        let segmented: Vec<_> = tokenizer.segment_str(text).collect();
+        assert_eq!(segmented, ["ال", "سلام", " ", "عليكم"]);
+
+        let mut segmenters = SegmenterRegistry::new();
+        assert!(segmenters.remove(Script::Arabic, Some(Language::Ara)).is_some());
+        assert!(segmenters.remove(Script::Arabic, Some(Language::Pes)).is_some());
+        assert!(!segmenters.contains(Script::Arabic, Some(Language::Ara)));
+
+        let mut builder = TokenizerBuilder::default();
+        builder.segmenters(segmenters);
+        let tokenizer = builder.build();
+        let segmented: Vec<_> = tokenizer.segment_str(text).collect();
+        assert_eq!(segmented, ["السلام", " ", "عليكم"]);
+    }
+
+    #[test]
+    fn empty_segmenter_registry() {
+        let mut builder = TokenizerBuilder::default();
+        builder.segmenters(SegmenterRegistry::empty());
+        let tokenizer = builder.build();
+
+        let segmented: Vec<_> = tokenizer.segment_str("السلام عليكم").collect();
+        assert_eq!(segmented, ["السلام", " ", "عليكم"]);
+    }
+
+    #[test]
+    fn language_confidence_threshold() {
+        // a word too short to be reliably detected as Russian or Ukrainian.
+        let text = "мир";
+
+        let mut segmenters = SegmenterRegistry::empty();
+        segmenters.insert(Script::Cyrillic, Some(Language::Rus), CharSegmenter);
+        segmenters.insert(Script::Cyrillic, Some(Language::Ukr), CharSegmenter);
+
+        let mut builder = TokenizerBuilder::default();
+        builder.segmenters(segmenters.clone());
+        let tokenizer = builder.build();
+        let segmented: Vec<_> = tokenizer.segment_str(text).collect();
+        assert_eq!(segmented, ["м", "и", "р"]);
+
+        let mut builder = TokenizerBuilder::default();
+        builder.segmenters(segmenters).language_confidence_threshold(0.9);
+        let tokenizer = builder.build();
+        let segmented: Vec<_> = tokenizer.segment_str(text).collect();
+        assert_eq!(segmented, ["мир"]);
+        let token = tokenizer.tokenize(text).next().unwrap();
+        assert_eq!(token.language, None);
+    }
+
+    #[test]
+    fn document_language_detection() {
+        // the first run only contains Kanji and is detected as Mandarin on its own.
+        let text = "東京 is the capital of Japan, 東京にはたくさんの人が住んでいます。";
+
+        let mut segmenters = SegmenterRegistry::empty();
+        segmenters.insert(Script::Cj, Some(Language::Cmn), CharSegmenter);
+        segmenters.insert(Script::Cj, Some(Language::Jpn), CharSegmenter);
+
+        let mut builder = TokenizerBuilder::default();
+        builder.segmenters(segmenters.clone());
+        let tokenizer = builder.build();
+        let languages: Vec<_> = tokenizer
+            .tokenize(text)
+            .filter(|t| t.script == Script::Cj)
+            .map(|t| t.language)
+            .collect();
+        assert_eq!(languages.first(), Some(&Some(Language::Cmn)));
+        assert_eq!(languages.last(), Some(&Some(Language::Jpn)));
+
+        let mut builder = TokenizerBuilder::default();
+        builder.segmenters(segmenters.clone()).document_language_detection(10);
+        let tokenizer = builder.build();
+        assert!(tokenizer
+            .tokenize(text)
+            .filter(|t| t.script == Script::Cj)
+            .all(|t| t.language == Some(Language::Jpn)));
+
+        // a long enough run overrides the language of the document.
+        let text =
+            "東京 and 今天天气很好，我们去公园散步吧。 and 東京にはたくさんの人が住んでいます。";
+        let mut builder = TokenizerBuilder::default();
+        builder.segmenters(segmenters).document_language_detection(5);
+        let tokenizer = builder.build();
+        let tokens: Vec<_> = tokenizer.tokenize(text).filter(|t| t.script == Script::Cj).collect();
+        let language_of =
+            |lemma: &str| tokens.iter().find(|t| t.lemma() == lemma).unwrap().language;
+        assert_eq!(language_of("京"), Some(Language::Jpn));
+        assert_eq!(language_of("天"), Some(Language::Cmn));
+        assert_eq!(language_of("ん"), Some(Language::Jpn));
+    }
+
+    #[test]
+    fn custom_language_detector() {
+        struct UkrainianDetector;
+
+        impl LanguageDetector for UkrainianDetector {
+            fn detect(&self, _text: &str, _: Option<&[Language]>) -> Option<(Language, f64)> {
+                Some((Language::Ukr, 1.0))
+            }
+        }
+
+        let mut segmenters = SegmenterRegistry::empty();
+        segmenters.insert(Script::Cyrillic, Some(Language::Rus), CharSegmenter);
+        segmenters.insert(Script::Cyrillic, Some(Language::Ukr), CharSegmenter);
+
+        let text = "Привет мир";
+        let detector = UkrainianDetector;
+        let mut builder = TokenizerBuilder::default();
+        builder.segmenters(segmenters).language_detector(&detector);
+        let tokenizer = builder.build();
+        assert_eq!(tokenizer.tokenize(text).next().unwrap().language, Some(Language::Ukr));
+    }
 }
@@ -0,0 +1,52 @@
+use std::sync::LazyLock;
+
+use fst::raw::Fst;
+
+use crate::segmenter::utils::{BufferingStrategy, FstSegmenter};
+use crate::segmenter::Segmenter;
+
+/// Myanmar specialized [`Segmenter`].
+///
+/// This Segmenter uses a dictionary encoded as an FST to segment the provided text.
+/// The dictionary is only a sample of common Burmese words, the unknown sequences are segmented by character,
+/// keeping the vowel signs and the medials with their consonant, see `dictionaries/txt/myanmar/README.md` for its origin.
+pub struct MyanmarSegmenter;
+
+static WORDS_FST: LazyLock<Fst<&[u8]>> = LazyLock::new(|| {
+    Fst::new(&include_bytes!("../../dictionaries/fst/myanmar/words.fst")[..]).unwrap()
+});
+
+static FST_SEGMENTER: LazyLock<FstSegmenter> = LazyLock::new(|| {
+    // the dictionary is incomplete, so the unknown sequences are segmented by cluster instead of being buffered
+    FstSegmenter::new(&WORDS_FST, BufferingStrategy::Cluster)
+});
+
+impl Segmenter for MyanmarSegmenter {
+    fn segment_str<'o>(&self, to_segment: &'o str) -> Box<dyn Iterator<Item = &'o str> + 'o> {
+        FST_SEGMENTER.segment_str(to_segment)
+    }
+}
+
+// Test the segmenter:
+#[cfg(test)]
+mod test {
+    use crate::segmenter::test::test_segmenter;
+
+    const TEXT: &str = "မင်္ဂလာပါ ကျွန်တော်မြန်မာစာကိုလေ့လာနေတယ် 123 456";
+
+    const SEGMENTED: &[&str] =
+        &["မင်္ဂလာပါ", " ", "ကျွန်တော်", "မြန်မာ", "စာ", "ကို", "လေ့လာ", "နေ", "တယ်", " ", "123", " ", "456"];
+
+    const TOKENIZED: &[&str] =
+        &["မင်္ဂလာပါ", " ", "ကျွန်တော်", "မြန်မာ", "စာ", "ကို", "လေ့လာ", "နေ", "တယ်", " ", "123", " ", "456"];
+
+    // Macro that run several tests on the Segmenter.
+    test_segmenter!(MyanmarSegmenter, TEXT, SEGMENTED, TOKENIZED, Script::Myanmar, Language::Mya);
+
+    #[test]
+    fn unknown_words() {
+        // `ဆရာဝန်` is missing from the dictionary.
+        let segmented: Vec<_> = MyanmarSegmenter.segment_str("ဆရာဝန်မြန်မာ").collect();
+        assert_eq!(segmented, ["ဆရာ", "ဝ", "န်", "မြန်မာ"]);
+    }
+}
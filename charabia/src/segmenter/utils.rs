@@ -1,6 +1,7 @@
 use std::num::NonZero;
 
 use fst::raw::{Fst, Output};
+use unicode_normalization::char::is_combining_mark;
 
 /// Final-state-transducer (FST) Segmenter
 pub(crate) struct FstSegmenter<'fst> {
@@ -48,6 +49,11 @@ impl<'fst> FstSegmenter<'fst> {
                                 return cursor.take_buffered_segment();
                             }
                         }
+                        BufferingStrategy::Cluster => {
+                            // return the next character with its combining marks
+                            cursor.buffer_next_cluster();
+                            return cursor.take_buffered_segment();
+                        }
                     }
                 }
             }
@@ -107,6 +113,9 @@ pub enum BufferingStrategy {
     /// - `Some(n)`: Buffer up to `n` characters before emitting the buffered sequence
     /// - `None`: Buffer indefinitely until a dictionary match is found
     UntilNextMatch { max_char_count: Option<NonZero<usize>> },
+    /// Emit each unmatched character with the combining marks following it,
+    /// like a consonant with its vowel signs, so a missing word never swallows the following text.
+    Cluster,
 }
 
 /// State of the buffer
@@ -171,6 +180,14 @@ impl<'o> SegmentationCursor<'o> {
         }
     }
 
+    /// buffer the next character and the combining marks following it
+    fn buffer_next_cluster(&mut self) {
+        self.buffer_next_character(None);
+        while self.tail().and_then(|tail| tail.chars().next()).is_some_and(is_combining_mark) {
+            self.buffer_next_character(None);
+        }
+    }
+
     /// get the length of the next character
     fn next_character_length(&self) -> usize {
         self.to_segment[self.offset..].chars().next().unwrap().len_utf8()
@@ -2,22 +2,24 @@ use std::borrow::Cow;
 
 #[cfg(test)]
 use quickcheck::{Arbitrary, Gen};
+use serde::ser::SerializeStruct;
+use serde::{Deserialize, Serialize, Serializer};
 
-use crate::detection::{Language, Script};
+use crate::detection::{ChineseVariant, Language, Script};
 
 /// Define the kind of a [`TokenKind::Separator`].
 ///
 /// A separator has two kinds:
 /// - `Hard`: Separate two tokens that are not in the same context (different phrases).
 /// - `Soft`: Separate two tokens that are in the same context (same phrase).
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum SeparatorKind {
     Hard,
     Soft,
 }
 
 /// Define the kind of a [`Token`].
-#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum TokenKind {
     Word,
     /// the token is a stop word,
@@ -43,7 +45,28 @@ impl Arbitrary for TokenKind {
     }
 }
 
-#[derive(Debug, Clone, Default, PartialEq, Eq)]
+/// Morphological information about a [`Token`],
+/// only given by the segmenters able to provide it, like the Japanese and Korean segmenters.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TokenMetadata {
+    /// part of speech of the Token, like `助詞` for a Japanese particle or `NNG` for a Korean noun.
+    pub part_of_speech: Option<String>,
+    /// dictionary form of the Token, like `食べる` for `食べ`.
+    pub base_form: Option<String>,
+    /// reading of the Token, in Katakana for Japanese and in Hangul for Korean.
+    pub reading: Option<String>,
+}
+
+/// A segmented and normalized part of the original text.
+///
+/// A `Token` can be serialized and deserialized with serde, the deserialized `lemma` is always owned.
+/// See [`Token::compact`] for a representation skipping the `char_map`, the `chinese_variant` and the `metadata`
+/// when they are `None` and `language_hinted` when it is `false`.
+///
+/// New fields can be added to a `Token` without a breaking change,
+/// create it from [`Token::default`] and match it with a `..` pattern.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct Token<'o> {
     /// kind of the Token assigned by the classifier
     pub kind: TokenKind,
@@ -56,14 +79,97 @@ pub struct Token<'o> {
     pub byte_end: usize,
     /// number of bytes used in the original string mapped to the number of bytes used in the normalized string by each char in the original string.
     /// The char_map must be the same length as the number of chars in the original lemma.
+    #[serde(default)]
     pub char_map: Option<Vec<(u8, u8)>>,
     /// script of the Token
     pub script: Script,
     /// language of the Token
     pub language: Option<Language>,
+    /// `true` if the language was given as a hint, see [`Tokenizer::tokenize_with_language`],
+    /// `false` if it was detected.
+    ///
+    /// [`Tokenizer::tokenize_with_language`]: crate::Tokenizer::tokenize_with_language
+    #[serde(default)]
+    pub language_hinted: bool,
+    /// variant of the Chinese ideographs of the original text, detected on the whole run of Chinese text,
+    /// `None` for the other scripts and languages or if the text doesn't tell one variant from the other.
+    #[serde(default)]
+    pub chinese_variant: Option<ChineseVariant>,
+    /// number of positions between the previous Token and this one,
+    /// `0` means that the Token is an alternative emitted at the same position as the previous one.
+    pub position_increment: usize,
+    /// number of positions spanned by the Token, greater than `1` when the Token is an alternative to several Tokens.
+    pub position_length: usize,
+    /// morphological information given by the segmenter, if any, boxed to keep the Token small.
+    #[serde(default)]
+    pub metadata: Option<Box<TokenMetadata>>,
+}
+
+impl Default for Token<'_> {
+    fn default() -> Self {
+        Self {
+            kind: TokenKind::default(),
+            lemma: Cow::default(),
+            char_start: 0,
+            char_end: 0,
+            byte_start: 0,
+            byte_end: 0,
+            char_map: None,
+            script: Script::default(),
+            language: None,
+            language_hinted: false,
+            chinese_variant: None,
+            position_increment: 1,
+            position_length: 1,
+            metadata: None,
+        }
+    }
+}
+
+impl<'o> Token<'o> {
+    /// Returns a serializable view of the Token skipping the `char_map`, the `chinese_variant` and the `metadata`
+    /// when they are `None` and `language_hinted` when it is `false`.
+    ///
+    /// The compact representation can be deserialized as a [`Token`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use charabia::{Token, Tokenize};
+    ///
+    /// let token = "hello".tokenize().next().unwrap();
+    /// let json = serde_json::to_string(&token.compact()).unwrap();
+    /// assert!(!json.contains("char_map"));
+    ///
+    /// let deserialized: Token = serde_json::from_str(&json).unwrap();
+    /// assert_eq!(deserialized, token);
+    /// ```
+    pub fn compact(&self) -> CompactToken<'_, 'o> {
+        CompactToken(self)
+    }
 }
 
 impl Token<'_> {
+    /// Converts the Token into a Token owning its lemma, no longer borrowing the original text.
+    pub fn into_owned(self) -> Token<'static> {
+        Token {
+            kind: self.kind,
+            lemma: Cow::Owned(self.lemma.into_owned()),
+            char_start: self.char_start,
+            char_end: self.char_end,
+            byte_start: self.byte_start,
+            byte_end: self.byte_end,
+            char_map: self.char_map,
+            script: self.script,
+            language: self.language,
+            language_hinted: self.language_hinted,
+            chinese_variant: self.chinese_variant,
+            position_increment: self.position_increment,
+            position_length: self.position_length,
+            metadata: self.metadata,
+        }
+    }
+
     /// Returns a reference over the normalized lemma.
     pub fn lemma(&self) -> &str {
         self.lemma.as_ref()
@@ -173,6 +279,50 @@ impl Token<'_> {
     }
 }
 
+/// Compact serializable representation of a [`Token`], see [`Token::compact`].
+#[derive(Debug, Clone, Copy)]
+pub struct CompactToken<'t, 'o>(pub &'t Token<'o>);
+
+impl Serialize for CompactToken<'_, '_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let token = self.0;
+        let len = 10
+            + token.char_map.is_some() as usize
+            + token.language_hinted as usize
+            + token.chinese_variant.is_some() as usize
+            + token.metadata.is_some() as usize;
+        let mut state = serializer.serialize_struct("Token", len)?;
+        state.serialize_field("kind", &token.kind)?;
+        state.serialize_field("lemma", &token.lemma)?;
+        state.serialize_field("char_start", &token.char_start)?;
+        state.serialize_field("char_end", &token.char_end)?;
+        state.serialize_field("byte_start", &token.byte_start)?;
+        state.serialize_field("byte_end", &token.byte_end)?;
+        match &token.char_map {
+            Some(char_map) => state.serialize_field("char_map", char_map)?,
+            None => state.skip_field("char_map")?,
+        }
+        state.serialize_field("script", &token.script)?;
+        state.serialize_field("language", &token.language)?;
+        if token.language_hinted {
+            state.serialize_field("language_hinted", &token.language_hinted)?;
+        } else {
+            state.skip_field("language_hinted")?;
+        }
+        match &token.chinese_variant {
+            Some(variant) => state.serialize_field("chinese_variant", variant)?,
+            None => state.skip_field("chinese_variant")?,
+        }
+        state.serialize_field("position_increment", &token.position_increment)?;
+        state.serialize_field("position_length", &token.position_length)?;
+        match &token.metadata {
+            Some(metadata) => state.serialize_field("metadata", metadata)?,
+            None => state.skip_field("metadata")?,
+        }
+        state.end()
+    }
+}
+
 #[cfg(test)]
 // WORKAROUND: The quickcheck macro can't be used with a type with lifetime.
 pub type StaticToken = Token<'static>;
@@ -200,6 +350,104 @@ impl Arbitrary for Token<'static> {
             char_map: None,
             script: Script::arbitrary(g),
             language: Option::arbitrary(g),
+            language_hinted: bool::arbitrary(g),
+            chinese_variant: *g
+                .choose(&[
+                    None,
+                    Some(ChineseVariant::Traditional),
+                    Some(ChineseVariant::Simplified),
+                ])
+                .unwrap(),
+            position_increment: 1,
+            position_length: 1,
+            metadata: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::borrow::Cow;
+
+    use super::{SeparatorKind, Token, TokenKind, TokenMetadata};
+    use crate::ChineseVariant;
+    use crate::{Language, Script};
+
+    fn token() -> Token<'static> {
+        Token {
+            kind: TokenKind::Separator(SeparatorKind::Hard),
+            lemma: Cow::Borrowed(". "),
+            char_start: 5,
+            char_end: 7,
+            byte_start: 5,
+            byte_end: 7,
+            script: Script::Latin,
+            language: Some(Language::Eng),
+            ..Default::default()
         }
     }
+
+    #[test]
+    fn serde_round_trip() {
+        let mut token = token();
+        token.char_map = Some(vec![(1, 1), (1, 1)]);
+
+        let json = serde_json::to_string(&token).unwrap();
+        let deserialized: Token = serde_json::from_str(&json).unwrap();
+        assert!(matches!(deserialized.lemma, Cow::Owned(_)));
+        assert_eq!(deserialized, token);
+
+        let json = serde_json::to_string(&token.compact()).unwrap();
+        assert!(json.contains("char_map"));
+        assert_eq!(serde_json::from_str::<Token>(&json).unwrap(), token);
+    }
+
+    #[test]
+    fn compact_skips_char_map() {
+        let token = token();
+
+        let json = serde_json::to_string(&token).unwrap();
+        assert!(json.contains(r#""char_map":null"#));
+
+        let json = serde_json::to_string(&token.compact()).unwrap();
+        assert!(!json.contains("char_map"));
+        assert_eq!(serde_json::from_str::<Token>(&json).unwrap(), token);
+        assert!(!json.contains("language_hinted"));
+
+        let token = Token { language_hinted: true, ..token };
+        let json = serde_json::to_string(&token.compact()).unwrap();
+        assert!(json.contains(r#""language_hinted":true"#));
+        assert_eq!(serde_json::from_str::<Token>(&json).unwrap(), token);
+        assert!(!json.contains("chinese_variant"));
+
+        let token = Token { chinese_variant: Some(ChineseVariant::Simplified), ..token };
+        let json = serde_json::to_string(&token.compact()).unwrap();
+        assert!(json.contains(r#""chinese_variant":"Simplified""#));
+        assert_eq!(serde_json::from_str::<Token>(&json).unwrap(), token);
+    }
+
+    #[test]
+    fn compact_skips_metadata() {
+        let token = token();
+        let json = serde_json::to_string(&token.compact()).unwrap();
+        assert!(!json.contains("metadata"));
+
+        let metadata = TokenMetadata {
+            part_of_speech: Some("助詞".to_string()),
+            base_form: Some("は".to_string()),
+            reading: Some("ハ".to_string()),
+        };
+        let token = Token { metadata: Some(Box::new(metadata)), ..token };
+        let json = serde_json::to_string(&token.compact()).unwrap();
+        assert!(json.contains(r#""part_of_speech":"助詞""#));
+        assert_eq!(serde_json::from_str::<Token>(&json).unwrap(), token);
+    }
+
+    #[quickcheck]
+    fn serde_arbitrary_round_trip(token: crate::StaticToken) -> bool {
+        let json = serde_json::to_string(&token).unwrap();
+        let compact = serde_json::to_string(&token.compact()).unwrap();
+        serde_json::from_str::<Token>(&json).unwrap() == token
+            && serde_json::from_str::<Token>(&compact).unwrap() == token
+    }
 }
@@ -1,11 +1,31 @@
 use std::borrow::Cow;
+use std::collections::{HashMap, VecDeque};
+use std::io::{self, BufRead};
+use std::sync::Arc;
 
 use aho_corasick::{AhoCorasick, MatchKind};
 use fst::Set;
 
-use crate::detection::Language;
-use crate::normalizer::{NormalizedTokenIter, NormalizerOption};
-use crate::segmenter::{Segment, SegmentedStrIter, SegmentedTokenIter, SegmenterOption};
+use crate::detection::{ChineseVariant, Language, LanguageDetector, Script};
+use crate::expander::{ExpandedTokenIter, Expander, ExpanderOption};
+#[cfg(feature = "chinese-normalization")]
+use crate::normalizer::ChineseNormalizer;
+#[cfg(feature = "stemming")]
+use crate::normalizer::StemmingNormalizer;
+use crate::normalizer::{
+    KoreanJamoMode, KoreanJamoNormalizer, LemmatizerNormalizer, Lexicon, NonspacingMarkNormalizer,
+    NormalizedTokenIter, Normalizer, NormalizerOption, NormalizerPipeline, CONTEXT_SEPARATOR_SET,
+};
+#[cfg(feature = "japanese")]
+use crate::segmenter::JapaneseUserDictSegmenter;
+#[cfg(feature = "korean")]
+use crate::segmenter::KoreanUserDictSegmenter;
+#[cfg(feature = "chinese-segmentation")]
+use crate::segmenter::{ChineseSegmentationMode, ChineseUserDictSegmenter, ChineseUserWord};
+use crate::segmenter::{
+    Segment, SegmentedStrIter, SegmentedTokenIter, SegmenterOption, SegmenterRegistry,
+    DEFAULT_SEPARATOR_AHO,
+};
 use crate::separators::DEFAULT_SEPARATORS;
 use crate::Token;
 
@@ -25,6 +45,217 @@ impl<'o> Iterator for ReconstructedTokenIter<'o, '_, '_, '_> {
     }
 }
 
+/// Default number of bytes read before tokenizing a chunk of a [`BufRead`].
+const READER_CHUNK_LEN: usize = 64 * 1024;
+
+/// Number of bytes after which a chunk without any hard separator is cut between two characters.
+const READER_MAX_CHUNK_LEN: usize = 16 * READER_CHUNK_LEN;
+
+/// Iterator over owned [`Token`]s of a text read from a [`BufRead`].
+///
+/// The text is read and tokenized by chunks of about 64KiB,
+/// chunks are cut after a hard separator, like `. ` or `?`, so words and expansions are never split.
+/// A text without any hard separator is cut between two characters once it reaches 1MiB,
+/// never in the middle of a UTF-8 character.
+/// The `byte_start`, `byte_end`, `char_start` and `char_end` of the tokens are offsets in the whole stream.
+///
+/// An [`io::Error`] is returned if the reader fails or if the text is not valid UTF-8,
+/// the iteration ends after the first error.
+pub struct ReaderTokenIter<'t, 'tb, R> {
+    tokenizer: &'t Tokenizer<'tb>,
+    /// language of each hinted script, see [`Tokenizer::tokenize_with_language`].
+    languages: Option<&'t HashMap<Script, Language>>,
+    reader: R,
+    chunk_len: usize,
+    max_chunk_len: usize,
+    /// bytes read but not tokenized yet.
+    buffer: Vec<u8>,
+    /// last tokenized chunk, starting at `chunk_offset` in the stream.
+    chunk: String,
+    chunk_offset: usize,
+    /// tokens of the last chunk not returned yet.
+    tokens: VecDeque<Token<'static>>,
+    byte_offset: usize,
+    char_offset: usize,
+    finished: bool,
+}
+
+impl<'t, 'tb, R: BufRead> ReaderTokenIter<'t, 'tb, R> {
+    fn new(
+        tokenizer: &'t Tokenizer<'tb>,
+        reader: R,
+        chunk_len: usize,
+        max_chunk_len: usize,
+    ) -> Self {
+        Self {
+            tokenizer,
+            languages: None,
+            reader,
+            chunk_len,
+            max_chunk_len,
+            buffer: Vec::new(),
+            chunk: String::new(),
+            chunk_offset: 0,
+            tokens: VecDeque::new(),
+            byte_offset: 0,
+            char_offset: 0,
+            finished: false,
+        }
+    }
+
+    /// Reads the next chunk of text to tokenize, returns `None` at the end of the stream.
+    fn next_chunk(&mut self) -> io::Result<Option<String>> {
+        // length of the buffer from which a cut is searched,
+        // doubled after each failed search to avoid scanning the same bytes again and again.
+        let mut cut_len = self.chunk_len;
+        loop {
+            let available = match self.reader.fill_buf() {
+                Ok(available) => available,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e),
+            };
+
+            // end of the stream, tokenize the remaining bytes.
+            if available.is_empty() {
+                if self.buffer.is_empty() {
+                    return Ok(None);
+                }
+                return into_string(std::mem::take(&mut self.buffer)).map(Some);
+            }
+
+            let len = available.len();
+            self.buffer.extend_from_slice(available);
+            self.reader.consume(len);
+
+            if self.buffer.len() >= cut_len.min(self.max_chunk_len) {
+                let aho = self.tokenizer.segmenter_option.aho.as_ref();
+                let cut = after_hard_separator(aho.unwrap_or(&DEFAULT_SEPARATOR_AHO), &self.buffer)
+                    .or_else(|| {
+                        (self.buffer.len() >= self.max_chunk_len)
+                            .then(|| before_last_char(&self.buffer))
+                            .flatten()
+                    });
+
+                match cut {
+                    Some(cut) => {
+                        let remaining = self.buffer.split_off(cut);
+                        let chunk = std::mem::replace(&mut self.buffer, remaining);
+                        return into_string(chunk).map(Some);
+                    }
+                    None => cut_len = self.buffer.len() * 2,
+                }
+            }
+        }
+    }
+
+    fn tokenize_chunk(&mut self, chunk: String) {
+        let (byte_offset, char_offset) = (self.byte_offset, self.char_offset);
+        let tokens = self.tokenizer.tokenize_with_hints(&chunk, self.languages);
+        self.tokens.extend(tokens.map(|token| {
+            let mut token = token.into_owned();
+            token.byte_start += byte_offset;
+            token.byte_end += byte_offset;
+            token.char_start += char_offset;
+            token.char_end += char_offset;
+            token
+        }));
+
+        self.chunk_offset = self.byte_offset;
+        self.byte_offset += chunk.len();
+        self.char_offset += chunk.chars().count();
+        self.chunk = chunk;
+    }
+
+    /// Same as iterating over `self` but attaches each [`Token`] to its corresponding portion of the original text.
+    pub fn reconstruct(self) -> ReaderReconstructedTokenIter<'t, 'tb, R> {
+        ReaderReconstructedTokenIter { inner: self }
+    }
+}
+
+impl<R: BufRead> Iterator for ReaderTokenIter<'_, '_, R> {
+    type Item = io::Result<Token<'static>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(token) = self.tokens.pop_front() {
+                return Some(Ok(token));
+            }
+
+            if self.finished {
+                return None;
+            }
+
+            match self.next_chunk() {
+                Ok(Some(chunk)) => self.tokenize_chunk(chunk),
+                Ok(None) => self.finished = true,
+                Err(e) => {
+                    self.finished = true;
+                    return Some(Err(e));
+                }
+            }
+        }
+    }
+}
+
+/// Iterator over tuples of [`String`] (part of the original text) and owned [`Token`] of a text read from a [`BufRead`].
+pub struct ReaderReconstructedTokenIter<'t, 'tb, R> {
+    inner: ReaderTokenIter<'t, 'tb, R>,
+}
+
+impl<R: BufRead> Iterator for ReaderReconstructedTokenIter<'_, '_, R> {
+    type Item = io::Result<(String, Token<'static>)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let token = match self.inner.next()? {
+            Ok(token) => token,
+            Err(e) => return Some(Err(e)),
+        };
+        // a chunk is only replaced once all its tokens are returned.
+        let offset = self.inner.chunk_offset;
+        let original =
+            self.inner.chunk[token.byte_start - offset..token.byte_end - offset].to_string();
+        Some(Ok((original, token)))
+    }
+}
+
+/// Returns the index after the last hard separator of the buffer, where it can be cut without changing the tokenization.
+///
+/// The separators are matched with the automaton of the tokenizer and the cut is made after the separators
+/// following the hard one, so they stay in the same script run as in the whole text.
+/// A match too close to the end of the buffer is ignored because the next bytes could make a longer one.
+fn after_hard_separator(aho: &AhoCorasick, buffer: &[u8]) -> Option<usize> {
+    let lookahead = aho.max_pattern_len();
+    let mut cut = None;
+    // end of the contiguous separators starting with a hard one.
+    let mut separators_end = None;
+    for m in aho.find_iter(buffer) {
+        if m.end() + lookahead > buffer.len() {
+            return cut;
+        }
+
+        if separators_end == Some(m.start()) {
+            separators_end = Some(m.end());
+        } else {
+            cut = separators_end.or(cut);
+            let is_hard = std::str::from_utf8(&buffer[m.range()])
+                .is_ok_and(|separator| CONTEXT_SEPARATOR_SET.contains(separator));
+            separators_end = is_hard.then_some(m.end());
+        }
+    }
+
+    separators_end.or(cut)
+}
+
+/// Returns the index of the last, maybe incomplete, character of the buffer.
+fn before_last_char(buffer: &[u8]) -> Option<usize> {
+    // the last byte that is not a UTF-8 continuation byte starts the last character.
+    buffer.iter().rposition(|b| (b & 0xC0) != 0x80).filter(|i| *i > 0)
+}
+
+fn into_string(bytes: Vec<u8>) -> io::Result<String> {
+    String::from_utf8(bytes).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
 /// Trait defining methods to tokenize a text.
 pub trait Tokenize<'o> {
     /// Creates an Iterator over [`Token`]s.
@@ -101,20 +332,16 @@ impl Tokenize<'_> for &str {
 pub struct Tokenizer<'tb> {
     segmenter_option: Cow<'tb, SegmenterOption<'tb>>,
     normalizer_option: Cow<'tb, NormalizerOption<'tb>>,
+    expander_option: ExpanderOption<'tb>,
 }
 
-impl Tokenizer<'_> {
+impl<'tb> Tokenizer<'tb> {
     /// Creates an Iterator over [`Token`]s.
     ///
     /// The provided text is segmented creating tokens,
     /// then tokens are normalized and classified depending on the list of normalizers and classifiers in [`normalizer::NORMALIZERS`].
     pub fn tokenize<'t, 'o>(&'t self, original: &'o str) -> NormalizedTokenIter<'o, 't, 't, 't> {
-        original
-            .segment_with_option(
-                self.segmenter_option.aho.as_ref(),
-                self.segmenter_option.allow_list,
-            )
-            .normalize(&self.normalizer_option)
+        self.segment(original).normalize(&self.normalizer_option)
     }
 
     /// Creates an Iterator over [`Token`]s.
@@ -130,11 +357,187 @@ impl Tokenizer<'_> {
         original: &'o str,
         allow_list: Option<&'lang [Language]>,
     ) -> NormalizedTokenIter<'o, 't, 'lang, 't> {
-        original
-            .segment_with_option(self.segmenter_option.aho.as_ref(), allow_list)
+        self.segment_tokens(self.segment_str_with_allow_list(original, allow_list))
             .normalize(&self.normalizer_option)
     }
 
+    /// Creates an Iterator over [`Token`]s using the given [`Language`] for each [`Script`] instead of detecting it.
+    ///
+    /// The language of a hinted script is never detected and the tokens of this script are marked as `language_hinted`,
+    /// the language of the other scripts is detected as in [`Tokenizer::tokenize`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    ///
+    /// use charabia::{Language, Script, TokenizerBuilder};
+    ///
+    /// let mut builder = TokenizerBuilder::default();
+    /// let tokenizer = builder.build();
+    ///
+    /// let languages = HashMap::from([(Script::Latin, Language::Fra)]);
+    /// let token = tokenizer.tokenize_with_language("chat", &languages).next().unwrap();
+    /// assert_eq!(token.language, Some(Language::Fra));
+    /// assert!(token.language_hinted);
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `languages` - the [`Language`] of each hinted [`Script`].
+    pub fn tokenize_with_language<'t, 'o>(
+        &'t self,
+        original: &'o str,
+        languages: &'t HashMap<Script, Language>,
+    ) -> NormalizedTokenIter<'o, 't, 't, 't> {
+        self.segment_tokens(
+            self.segment_str_with_allow_list(original, self.segmenter_option.allow_list)
+                .with_language_hints(languages),
+        )
+        .normalize(&self.normalizer_option)
+    }
+
+    fn tokenize_with_hints<'t, 'o>(
+        &'t self,
+        original: &'o str,
+        languages: Option<&'t HashMap<Script, Language>>,
+    ) -> NormalizedTokenIter<'o, 't, 't, 't> {
+        match languages {
+            Some(languages) => self.tokenize_with_language(original, languages),
+            None => self.tokenize(original),
+        }
+    }
+
+    /// Creates an Iterator over owned [`Token`]s of a text read from a [`BufRead`].
+    ///
+    /// Unlike [`tokenize`], the whole text doesn't need to be loaded in memory,
+    /// it is read and tokenized by chunks cut after hard separators, see [`ReaderTokenIter`].
+    /// The offsets of the returned tokens are relative to the start of the stream.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::io::Cursor;
+    ///
+    /// use charabia::TokenizerBuilder;
+    ///
+    /// let mut builder = TokenizerBuilder::default();
+    /// let tokenizer = builder.build();
+    ///
+    /// let reader = Cursor::new("The quick fox\njumps over the lazy dog");
+    /// let tokens: Vec<_> = tokenizer.tokenize_reader(reader).collect::<Result<_, _>>().unwrap();
+    ///
+    /// let dog = tokens.last().unwrap();
+    /// assert_eq!(dog.lemma(), "dog");
+    /// assert_eq!((dog.byte_start, dog.byte_end), (34, 37));
+    /// ```
+    pub fn tokenize_reader<'t, R: BufRead>(&'t self, reader: R) -> ReaderTokenIter<'t, 'tb, R> {
+        ReaderTokenIter::new(self, reader, READER_CHUNK_LEN, READER_MAX_CHUNK_LEN)
+    }
+
+    /// Same as [`Tokenizer::tokenize_reader`] but uses the given [`Language`] for each [`Script`],
+    /// see [`Tokenizer::tokenize_with_language`].
+    pub fn tokenize_reader_with_language<'t, R: BufRead>(
+        &'t self,
+        reader: R,
+        languages: &'t HashMap<Script, Language>,
+    ) -> ReaderTokenIter<'t, 'tb, R> {
+        ReaderTokenIter { languages: Some(languages), ..self.tokenize_reader(reader) }
+    }
+
+    /// Tokenizes a batch of documents, returning the [`Token`]s of each document in the input order.
+    ///
+    /// When the `rayon` feature is enabled, the documents are tokenized in parallel
+    /// on the global rayon thread pool, all the threads sharing the configuration of this `Tokenizer`.
+    /// Otherwise, the documents are tokenized one after the other.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use charabia::TokenizerBuilder;
+    ///
+    /// let mut builder = TokenizerBuilder::default();
+    /// let tokenizer = builder.build();
+    ///
+    /// let documents = ["Hello world!", "The quick fox"];
+    /// let tokens = tokenizer.tokenize_batch(&documents);
+    ///
+    /// assert_eq!(tokens.len(), 2);
+    /// assert_eq!(tokens[0][0].lemma(), "hello");
+    /// assert_eq!(tokens[1][0].lemma(), "the");
+    /// ```
+    pub fn tokenize_batch<'o, I, D>(&self, documents: I) -> Vec<Vec<Token<'o>>>
+    where
+        I: IntoIterator<Item = &'o D>,
+        D: AsRef<str> + Sync + ?Sized + 'o,
+    {
+        self.tokenize_batch_with_hints(documents, None)
+    }
+
+    /// Same as [`Tokenizer::tokenize_batch`] but uses the given [`Language`] for each [`Script`] of every document,
+    /// see [`Tokenizer::tokenize_with_language`].
+    pub fn tokenize_batch_with_language<'o, I, D>(
+        &self,
+        documents: I,
+        languages: &HashMap<Script, Language>,
+    ) -> Vec<Vec<Token<'o>>>
+    where
+        I: IntoIterator<Item = &'o D>,
+        D: AsRef<str> + Sync + ?Sized + 'o,
+    {
+        self.tokenize_batch_with_hints(documents, Some(languages))
+    }
+
+    fn tokenize_batch_with_hints<'o, I, D>(
+        &self,
+        documents: I,
+        languages: Option<&HashMap<Script, Language>>,
+    ) -> Vec<Vec<Token<'o>>>
+    where
+        I: IntoIterator<Item = &'o D>,
+        D: AsRef<str> + Sync + ?Sized + 'o,
+    {
+        #[cfg(feature = "rayon")]
+        {
+            use rayon::prelude::*;
+
+            let documents: Vec<&'o D> = documents.into_iter().collect();
+            documents
+                .into_par_iter()
+                .map(|document| self.tokenize_with_hints(document.as_ref(), languages).collect())
+                .collect()
+        }
+
+        #[cfg(not(feature = "rayon"))]
+        {
+            documents
+                .into_iter()
+                .map(|document| self.tokenize_with_hints(document.as_ref(), languages).collect())
+                .collect()
+        }
+    }
+
+    /// Same as [`tokenize`] but emits the alternatives given by the configured [`Expander`]s.
+    ///
+    /// Alternatives share the `byte_start` and `byte_end` of the tokens they replace,
+    /// see [`ExpandedTokenIter`] for more details about their positions.
+    pub fn tokenize_expanded<'t, 'o>(
+        &'t self,
+        original: &'o str,
+    ) -> ExpandedTokenIter<'o, 't, 't, 't> {
+        self.tokenize(original).expand(original, self.expander_option)
+    }
+
+    /// Same as [`Tokenizer::tokenize_expanded`] but uses the given [`Language`] for each [`Script`],
+    /// the alternatives keep the language of the tokens they replace, see [`Tokenizer::tokenize_with_language`].
+    pub fn tokenize_expanded_with_language<'t, 'o>(
+        &'t self,
+        original: &'o str,
+        languages: &'t HashMap<Script, Language>,
+    ) -> ExpandedTokenIter<'o, 't, 't, 't> {
+        self.tokenize_with_language(original, languages).expand(original, self.expander_option)
+    }
+
     /// Same as [`tokenize`] but attaches each [`Token`] to its corresponding portion of the original text.
     pub fn reconstruct<'t, 'o>(
         &'t self,
@@ -145,18 +548,55 @@ impl Tokenizer<'_> {
 
     /// Segments the provided text creating an Iterator over [`Token`].
     pub fn segment<'t, 'o>(&'t self, original: &'o str) -> SegmentedTokenIter<'o, 't, 't> {
-        original.segment_with_option(
-            self.segmenter_option.aho.as_ref(),
-            self.segmenter_option.allow_list,
-        )
+        self.segment_tokens(self.segment_str(original))
+    }
+
+    /// Creates the [`Token`]s of the given segments, keeping the metadata given by the segmenters if configured.
+    fn segment_tokens<'t, 'o, 'lang>(
+        &'t self,
+        segments: SegmentedStrIter<'o, 't, 'lang>,
+    ) -> SegmentedTokenIter<'o, 't, 'lang> {
+        segments.with_metadata(self.segmenter_option.with_metadata).into()
     }
 
     /// Segments the provided text creating an Iterator over `&str`.
     pub fn segment_str<'t, 'o>(&'t self, original: &'o str) -> SegmentedStrIter<'o, 't, 't> {
-        original.segment_str_with_option(
+        self.segment_str_with_allow_list(original, self.segmenter_option.allow_list)
+    }
+
+    /// Returns `true` if a normalizer needs the language of the tokens, like the lemmatizer and the stemmer.
+    fn needs_language(&self) -> bool {
+        let normalizers = self.normalizer_option.normalizers();
+        #[cfg(feature = "stemming")]
+        if normalizers.contains::<StemmingNormalizer>() {
+            return true;
+        }
+
+        normalizers.contains::<LemmatizerNormalizer>()
+    }
+
+    fn segment_str_with_allow_list<'t, 'o, 'lang>(
+        &'t self,
+        original: &'o str,
+        allow_list: Option<&'lang [Language]>,
+    ) -> SegmentedStrIter<'o, 't, 'lang> {
+        let iter = SegmentedStrIter::with_segmenters(
+            original,
             self.segmenter_option.aho.as_ref(),
-            self.segmenter_option.allow_list,
+            allow_list,
+            &self.segmenter_option.segmenters,
         )
+        .with_min_language_confidence(self.segmenter_option.min_language_confidence)
+        .with_language_detection(self.needs_language());
+        let iter = match self.segmenter_option.language_detector {
+            Some(language_detector) => iter.with_language_detector(language_detector),
+            None => iter,
+        };
+
+        match self.segmenter_option.document_language_min_run_len {
+            Some(min_run_len) => iter.with_document_language_detection(min_run_len),
+            None => iter,
+        }
     }
 }
 
@@ -191,7 +631,16 @@ pub struct TokenizerBuilder<'tb, A> {
     stop_words: Option<&'tb Set<A>>,
     words_dict: Option<&'tb [&'tb str]>,
     normalizer_option: NormalizerOption<'tb>,
+    normalizers: Option<&'tb NormalizerPipeline>,
+    lemmatizer: Option<LemmatizerNormalizer>,
+    chinese_fold: Option<ChineseVariant>,
+    korean_jamo: Option<KoreanJamoMode>,
+    #[cfg(feature = "stemming")]
+    stemming: bool,
     segmenter_option: SegmenterOption<'tb>,
+    expander_option: ExpanderOption<'tb>,
+    #[cfg(feature = "chinese-segmentation")]
+    chinese_segmenter: Option<ChineseUserDictSegmenter>,
 }
 
 impl<'tb, A> TokenizerBuilder<'tb, A> {
@@ -200,10 +649,19 @@ impl<'tb, A> TokenizerBuilder<'tb, A> {
     /// if you don't plan to set stop_words, prefer use [`TokenizerBuilder::default`]
     pub fn new() -> TokenizerBuilder<'tb, A> {
         Self {
-            normalizer_option: crate::normalizer::DEFAULT_NORMALIZER_OPTION,
+            normalizer_option: NormalizerOption::new(),
+            normalizers: None,
+            lemmatizer: None,
+            chinese_fold: None,
+            korean_jamo: None,
+            #[cfg(feature = "stemming")]
+            stemming: false,
             segmenter_option: SegmenterOption::default(),
+            expander_option: ExpanderOption::default(),
             stop_words: None,
             words_dict: None,
+            #[cfg(feature = "chinese-segmentation")]
+            chinese_segmenter: None,
         }
     }
 }
@@ -223,6 +681,41 @@ impl<'tb, A: AsRef<[u8]>> TokenizerBuilder<'tb, A> {
         self
     }
 
+    /// Configure the parts of speech of the words that will be classified as `TokenKind::StopWord`.
+    ///
+    /// Only the tokens segmented by a segmenter giving their part of speech, like the Japanese and Korean segmenters,
+    /// can be classified this way, a part of speech matches if it starts with one of the given ones.
+    /// The separators are never classified as stop words.
+    /// The tokens keep their metadata, as with [`TokenizerBuilder::token_metadata`].
+    ///
+    /// # Arguments
+    ///
+    /// * `stop_parts_of_speech` - a slice of parts of speech,
+    ///   like [`JAPANESE_STOP_PARTS_OF_SPEECH`] or [`KOREAN_STOP_PARTS_OF_SPEECH`].
+    ///
+    /// [`JAPANESE_STOP_PARTS_OF_SPEECH`]: crate::normalizer::JAPANESE_STOP_PARTS_OF_SPEECH
+    /// [`KOREAN_STOP_PARTS_OF_SPEECH`]: crate::normalizer::KOREAN_STOP_PARTS_OF_SPEECH
+    pub fn stop_parts_of_speech(&mut self, stop_parts_of_speech: &'tb [&'tb str]) -> &mut Self {
+        self.normalizer_option.classifier.stop_parts_of_speech = Some(stop_parts_of_speech);
+        self.segmenter_option.with_metadata = true;
+        self
+    }
+
+    /// Configure whether the tokens keep the [`TokenMetadata`] given by the segmenters,
+    /// like the part of speech, the base form and the reading of the Japanese and Korean words.
+    ///
+    /// The metadata are dropped by default, sparing their allocations.
+    ///
+    /// [`TokenMetadata`]: crate::TokenMetadata
+    ///
+    /// # Arguments
+    ///
+    /// * `with_metadata` - if `true`, the tokens have the metadata given by the segmenters.
+    pub fn token_metadata(&mut self, with_metadata: bool) -> &mut Self {
+        self.segmenter_option.with_metadata = with_metadata;
+        self
+    }
+
     /// Configure the words that will be used to separate words and classified as `TokenKind::Separator`.
     ///
     /// # Arguments
@@ -327,6 +820,109 @@ impl<'tb, A: AsRef<[u8]>> TokenizerBuilder<'tb, A> {
         self
     }
 
+    /// Configure the [`Normalizer`]s applied on each token and their order.
+    ///
+    /// By default, the normalizers listed in [`NORMALIZERS`] are used.
+    /// Note that the lossy normalizers of the pipeline are still only applied when the lossy normalization is enabled,
+    /// and that the normalizers configured on the builder, like the [`Lexicon`]s, are added to the given pipeline.
+    ///
+    /// [`Normalizer`]: crate::normalizer::Normalizer
+    /// [`NORMALIZERS`]: crate::normalizer::NORMALIZERS
+    ///
+    /// # Arguments
+    ///
+    /// * `normalizers` - a [`NormalizerPipeline`] containing the normalizers to use.
+    pub fn normalizers(&mut self, normalizers: &'tb NormalizerPipeline) -> &mut Self {
+        self.normalizers = Some(normalizers);
+        self
+    }
+
+    /// Configure the [`Lexicon`] used to lemmatize the tokens of a [`Language`].
+    ///
+    /// Calling this method again with the same `Language` replaces the previous `Lexicon`.
+    /// The [`Language`] of every token is detected once a `Lexicon` is configured,
+    /// an allow list restricts the detected languages.
+    ///
+    /// # Arguments
+    ///
+    /// * `language` - the [`Language`] of the tokens to lemmatize.
+    /// * `lexicon` - a [`Lexicon`] mapping the surface forms to their lemma, it can be shared using an [`Arc`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use charabia::normalizer::Lexicon;
+    /// use charabia::{Language, TokenizerBuilder};
+    ///
+    /// let lexicon = Lexicon::from_pairs([("went", "go"), ("gone", "go")]).unwrap();
+    /// let allow_list = [Language::Eng];
+    ///
+    /// let mut builder = TokenizerBuilder::default();
+    /// builder.lexicon(Language::Eng, lexicon).allow_list(&allow_list);
+    /// let tokenizer = builder.build();
+    ///
+    /// let output: Vec<_> = tokenizer.tokenize("Went gone").map(|t| t.lemma().to_string()).collect();
+    /// assert_eq!(output, ["go", " ", "go"]);
+    /// ```
+    pub fn lexicon(&mut self, language: Language, lexicon: impl Into<Arc<Lexicon>>) -> &mut Self {
+        let lemmatizer = self.lemmatizer.take().unwrap_or_default();
+        self.lemmatizer = Some(lemmatizer.with_lexicon(language, lexicon));
+        self
+    }
+
+    /// Configure the [`Expander`]s emitting alternatives of the normalized tokens.
+    ///
+    /// Alternatives are only emitted by [`Tokenizer::tokenize_expanded`].
+    ///
+    /// # Arguments
+    ///
+    /// * `expanders` - a slice of [`Expander`]s, applied in the given order.
+    pub fn expanders(&mut self, expanders: &'tb [Box<dyn Expander>]) -> &mut Self {
+        self.expander_option.expanders = expanders;
+        self
+    }
+
+    /// Configure the variant the Chinese ideographs are folded to during the normalization.
+    ///
+    /// By default, the Simplified ideographs are folded to their Traditional form,
+    /// the detected variant of the original text is kept in the [`Token::chinese_variant`] of the tokens.
+    ///
+    /// # Arguments
+    ///
+    /// * `variant` - the [`ChineseVariant`] the ideographs are folded to.
+    pub fn chinese_fold(&mut self, variant: ChineseVariant) -> &mut Self {
+        self.chinese_fold = Some(variant);
+        self
+    }
+
+    /// Configure the decomposition of the Hangul syllables during the normalization.
+    ///
+    /// By default, the syllables are only decomposed by the compatibility decomposition,
+    /// see [`KoreanJamoMode`] for the available decompositions.
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - the [`KoreanJamoMode`] applied to the Korean tokens.
+    pub fn korean_jamo(&mut self, mode: KoreanJamoMode) -> &mut Self {
+        self.korean_jamo = Some(mode);
+        self
+    }
+
+    /// Enable the reduction of the words to their stem during the normalization.
+    ///
+    /// The stemming is disabled by default, when enabled the [`Language`] of every token is detected
+    /// and the tokens in a [`Language`] without stemming algorithm are kept as is,
+    /// see [`StemmingNormalizer`](crate::normalizer::StemmingNormalizer) for the supported languages.
+    ///
+    /// # Arguments
+    ///
+    /// * `stemming` - whether the words are reduced to their stem.
+    #[cfg(feature = "stemming")]
+    pub fn stemming(&mut self, stemming: bool) -> &mut Self {
+        self.stemming = stemming;
+        self
+    }
+
     /// Configure which languages can be used for which script
     ///
     /// # Arguments
@@ -337,8 +933,196 @@ impl<'tb, A: AsRef<[u8]>> TokenizerBuilder<'tb, A> {
         self
     }
 
+    /// Configure the [`LanguageDetector`] used to detect the language of the texts.
+    ///
+    /// By default, the languages are detected by the [`WhatlangDetector`],
+    /// a [`NgramDetector`] trained on local corpora can be used instead.
+    ///
+    /// [`WhatlangDetector`]: crate::WhatlangDetector
+    /// [`NgramDetector`]: crate::NgramDetector
+    ///
+    /// # Arguments
+    ///
+    /// * `language_detector` - the [`LanguageDetector`] to use.
+    pub fn language_detector(&mut self, language_detector: &'tb dyn LanguageDetector) -> &mut Self {
+        self.segmenter_option.language_detector = Some(language_detector);
+        self
+    }
+
+    /// Configure the minimum confidence of the language detection.
+    ///
+    /// When the detected language of a text has a lower confidence,
+    /// the text is segmented by the default segmenter of its script instead of guessing its language.
+    ///
+    /// # Arguments
+    ///
+    /// * `min_confidence` - a value between `0.0` and `1.0`, `0.0` by default.
+    pub fn language_confidence_threshold(&mut self, min_confidence: f64) -> &mut Self {
+        self.segmenter_option.min_language_confidence = min_confidence;
+        self
+    }
+
+    /// Detect the language of each script once on the whole text instead of on each run of this script.
+    ///
+    /// The detected language is used for every run of the script,
+    /// so a short run, like a Kanji word in a Japanese text, isn't detected as another language.
+    /// A run containing at least `min_run_len` characters still uses its own language if it is reliably detected.
+    ///
+    /// # Arguments
+    ///
+    /// * `min_run_len` - the number of characters a run needs to override the language of the document.
+    pub fn document_language_detection(&mut self, min_run_len: usize) -> &mut Self {
+        self.segmenter_option.document_language_min_run_len = Some(min_run_len);
+        self
+    }
+
+    /// Configure the [`Segmenter`]s picked for each [`Script`] and [`Language`].
+    ///
+    /// By default, the segmenters listed in [`SEGMENTERS`] are used.
+    ///
+    /// [`Segmenter`]: crate::segmenter::Segmenter
+    /// [`Script`]: crate::Script
+    /// [`SEGMENTERS`]: crate::segmenter::SEGMENTERS
+    ///
+    /// # Arguments
+    ///
+    /// * `segmenters` - a [`SegmenterRegistry`] containing the segmenters to use.
+    pub fn segmenters(&mut self, segmenters: SegmenterRegistry) -> &mut Self {
+        self.segmenter_option.segmenters = segmenters;
+        #[cfg(feature = "chinese-segmentation")]
+        {
+            self.chinese_segmenter = None;
+        }
+        self
+    }
+
+    /// Configure words to add to the dictionary used to segment Chinese,
+    /// only the built `Tokenizer` is impacted.
+    ///
+    /// The words are added to a copy of the default dictionary assigned to the Chinese languages,
+    /// calling [`TokenizerBuilder::segmenters`] afterward resets them.
+    ///
+    /// # Arguments
+    ///
+    /// * `words` - a list of [`ChineseUserWord`]s with their optional frequency and tag.
+    #[cfg(feature = "chinese-segmentation")]
+    pub fn chinese_user_words<'w>(
+        &mut self,
+        words: impl IntoIterator<Item = ChineseUserWord<'w>>,
+    ) -> &mut Self {
+        let mut segmenter = self.take_chinese_segmenter();
+        segmenter.add_words(words);
+        self.set_chinese_segmenter(segmenter)
+    }
+
+    /// Configure how Chinese is segmented, [`ChineseSegmentationMode::Search`] by default.
+    ///
+    /// The [`ChineseSegmentationMode::Full`] mode returns overlapping tokens positioned on the boundaries of the words,
+    /// a token's `position_increment` is the number of word boundaries separating it from the previous token,
+    /// and its `position_length` is the number of word boundaries it spans.
+    /// Calling [`TokenizerBuilder::segmenters`] afterward resets the mode.
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - the [`ChineseSegmentationMode`] to use.
+    #[cfg(feature = "chinese-segmentation")]
+    pub fn chinese_segmentation_mode(&mut self, mode: ChineseSegmentationMode) -> &mut Self {
+        let segmenter = self.take_chinese_segmenter().with_mode(mode);
+        self.set_chinese_segmenter(segmenter)
+    }
+
+    /// Configure the [`Segmenter`] of Japanese, allowing to use a user dictionary or another [`LinderaMode`],
+    /// only the built `Tokenizer` is impacted.
+    ///
+    /// Calling [`TokenizerBuilder::segmenters`] afterward resets it.
+    ///
+    /// [`Segmenter`]: crate::segmenter::Segmenter
+    /// [`LinderaMode`]: crate::segmenter::LinderaMode
+    ///
+    /// # Arguments
+    ///
+    /// * `segmenter` - the [`JapaneseUserDictSegmenter`] to use.
+    #[cfg(feature = "japanese")]
+    pub fn japanese_segmenter(&mut self, segmenter: JapaneseUserDictSegmenter) -> &mut Self {
+        self.segmenter_option.segmenters.insert(Script::Cj, Some(Language::Jpn), segmenter);
+        self
+    }
+
+    /// Configure the [`Segmenter`] of Korean, allowing to use a user dictionary or another [`LinderaMode`],
+    /// only the built `Tokenizer` is impacted.
+    ///
+    /// Calling [`TokenizerBuilder::segmenters`] afterward resets it.
+    ///
+    /// [`Segmenter`]: crate::segmenter::Segmenter
+    /// [`LinderaMode`]: crate::segmenter::LinderaMode
+    ///
+    /// # Arguments
+    ///
+    /// * `segmenter` - the [`KoreanUserDictSegmenter`] to use.
+    #[cfg(feature = "korean")]
+    pub fn korean_segmenter(&mut self, segmenter: KoreanUserDictSegmenter) -> &mut Self {
+        self.segmenter_option.segmenters.insert(Script::Hangul, Some(Language::Kor), segmenter);
+        self
+    }
+
+    /// Takes the configured Chinese segmenter out of the registry,
+    /// its dictionary is then only referenced once and can be extended without being copied.
+    #[cfg(feature = "chinese-segmentation")]
+    fn take_chinese_segmenter(&mut self) -> ChineseUserDictSegmenter {
+        if self.chinese_segmenter.is_some() {
+            let segmenters = &mut self.segmenter_option.segmenters;
+            segmenters.remove(Script::Cj, Some(Language::Cmn));
+            segmenters.remove(Script::Cj, Some(Language::Zho));
+        }
+        self.chinese_segmenter.take().unwrap_or_default()
+    }
+
+    #[cfg(feature = "chinese-segmentation")]
+    fn set_chinese_segmenter(&mut self, segmenter: ChineseUserDictSegmenter) -> &mut Self {
+        let segmenters = &mut self.segmenter_option.segmenters;
+        segmenters.insert(Script::Cj, Some(Language::Cmn), segmenter.clone());
+        segmenters.insert(Script::Cj, Some(Language::Zho), segmenter.clone());
+        self.chinese_segmenter = Some(segmenter);
+        self
+    }
+
+    /// Adds the normalizers configured on the builder to the given pipeline or to the default one.
+    fn compose_normalizers(&self) -> Option<Cow<'tb, NormalizerPipeline>> {
+        #[cfg(feature = "stemming")]
+        let stemming = self.stemming;
+        #[cfg(not(feature = "stemming"))]
+        let stemming = false;
+        if self.lemmatizer.is_none()
+            && self.chinese_fold.is_none()
+            && self.korean_jamo.is_none()
+            && !stemming
+        {
+            return self.normalizers.map(Cow::Borrowed);
+        }
+
+        let mut normalizers = self.normalizers.cloned().unwrap_or_default();
+        #[cfg(feature = "chinese-normalization")]
+        if let Some(variant) = self.chinese_fold {
+            normalizers.replace::<ChineseNormalizer, _>(ChineseNormalizer::new(variant));
+        }
+        if let Some(mode) = self.korean_jamo {
+            insert_normalizer(&mut normalizers, KoreanJamoNormalizer::new(mode));
+        }
+        if let Some(lemmatizer) = &self.lemmatizer {
+            insert_normalizer(&mut normalizers, lemmatizer.clone());
+        }
+        #[cfg(feature = "stemming")]
+        if self.stemming {
+            insert_normalizer(&mut normalizers, StemmingNormalizer);
+        }
+
+        Some(Cow::Owned(normalizers))
+    }
+
     /// Build the configurated `Tokenizer`.
     pub fn build(&mut self) -> Tokenizer<'_> {
+        self.normalizer_option.normalizers = self.compose_normalizers();
+
         // If a custom list of separators or/and a custom list of words have been given,
         // then an Aho-Corasick automaton is created to pre-segment the text during the tokenization process
         // TODO: avoid recreating the automaton if nothing changed
@@ -371,6 +1155,7 @@ impl<'tb, A: AsRef<[u8]>> TokenizerBuilder<'tb, A> {
         Tokenizer {
             normalizer_option: Cow::Borrowed(&self.normalizer_option),
             segmenter_option: Cow::Borrowed(&self.segmenter_option),
+            expander_option: self.expander_option,
         }
     }
 
@@ -383,10 +1168,23 @@ impl<'tb, A: AsRef<[u8]>> TokenizerBuilder<'tb, A> {
         Tokenizer {
             normalizer_option: Cow::Owned(self.normalizer_option),
             segmenter_option: Cow::Owned(self.segmenter_option),
+            expander_option: self.expander_option,
         }
     }
 }
 
+/// Inserts a lossy normalizer before the [`NonspacingMarkNormalizer`],
+/// replacing the normalizer of the same type if the pipeline already contains one.
+fn insert_normalizer<N: Normalizer + 'static>(normalizers: &mut NormalizerPipeline, normalizer: N) {
+    if normalizers.contains::<N>() {
+        normalizers.replace::<N, _>(normalizer);
+    } else if normalizers.contains::<NonspacingMarkNormalizer>() {
+        normalizers.insert_before::<NonspacingMarkNormalizer, _>(normalizer);
+    } else {
+        normalizers.push_lossy(normalizer);
+    }
+}
+
 impl Default for TokenizerBuilder<'_, Vec<u8>> {
     fn default() -> Self {
         Self::new()
@@ -395,10 +1193,16 @@ impl Default for TokenizerBuilder<'_, Vec<u8>> {
 
 #[cfg(test)]
 mod test {
+    use std::collections::HashMap;
+    use std::io::{self, BufReader};
+
     use fst::Set;
     use quickcheck::quickcheck;
 
-    use crate::{Tokenize, TokenizerBuilder};
+    use super::{ReaderTokenIter, READER_MAX_CHUNK_LEN};
+    use crate::expander::{Expander, SynonymExpander};
+    use crate::normalizer::KoreanJamoMode;
+    use crate::{ChineseVariant, Language, Script, Token, Tokenize, TokenizerBuilder};
 
     #[test]
     fn check_lifetimes() {
@@ -430,6 +1234,300 @@ mod test {
         assert_eq!(tokens.iter().last().map(|t| t.lemma()), Some("."));
     }
 
+    #[test]
+    fn tokenize_reader_like_tokenize() {
+        let text = "The quick (\"brown\") fox can't jump 32.3 feet, right? Brr, it's 29.3°F!\n\
+                    Привет мир, 你好世界。السلام عليكم   hello\tworld";
+        let mut builder = TokenizerBuilder::default();
+        let tokenizer = builder.build();
+        let expected: Vec<_> = tokenizer.tokenize(text).map(Token::into_owned).collect();
+
+        // read the text by pieces of every size to cut it after each hard separator.
+        for chunk_len in 1..=text.len() {
+            let reader = BufReader::with_capacity(chunk_len, text.as_bytes());
+            let reader = ReaderTokenIter::new(&tokenizer, reader, chunk_len, READER_MAX_CHUNK_LEN);
+            let tokens: Vec<_> = reader.collect::<io::Result<_>>().unwrap();
+            assert_eq!(tokens, expected, "chunk_len: {chunk_len}");
+        }
+    }
+
+    #[test]
+    fn tokenize_reader_without_hard_separator() {
+        let text = "été déjà vu\n".repeat(100) + "東京は日本の首都です";
+        let mut builder = TokenizerBuilder::default();
+        let tokenizer = builder.build();
+
+        let reader = BufReader::with_capacity(7, text.as_bytes());
+        let reader = ReaderTokenIter::new(&tokenizer, reader, 10, 100);
+        let tokens: Vec<_> = reader.collect::<io::Result<_>>().unwrap();
+
+        let chars: Vec<_> = text.chars().collect();
+        for token in &tokens {
+            let original = &text[token.byte_start..token.byte_end];
+            let original_chars: String = chars[token.char_start..token.char_end].iter().collect();
+            assert_eq!(original, original_chars);
+        }
+        assert_eq!(tokens.last().unwrap().byte_end, text.len());
+        assert_eq!(tokens.last().unwrap().char_end, chars.len());
+    }
+
+    #[test]
+    fn tokenize_reader_reconstruct() {
+        let text = "Hello world! Привет мир, 你好世界。";
+        let mut builder = TokenizerBuilder::default();
+        let tokenizer = builder.build();
+
+        let reader = BufReader::with_capacity(4, text.as_bytes());
+        let reader = ReaderTokenIter::new(&tokenizer, reader, 4, READER_MAX_CHUNK_LEN);
+        let pairs: Vec<_> = reader.reconstruct().collect::<io::Result<_>>().unwrap();
+        let expected: Vec<_> = tokenizer
+            .reconstruct(text)
+            .map(|(original, token)| (original.to_string(), token.into_owned()))
+            .collect();
+        assert_eq!(pairs, expected);
+    }
+
+    #[test]
+    fn tokenize_reader_invalid_utf8() {
+        let mut builder = TokenizerBuilder::default();
+        let tokenizer = builder.build();
+
+        let bytes: &[u8] = b"hello\nw\xFFrld";
+        let mut tokens = tokenizer.tokenize_reader(bytes);
+        let error = tokens.find_map(Result::err).unwrap();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        assert!(tokens.next().is_none());
+    }
+
+    #[test]
+    fn tokenize_batch_keeps_order() {
+        let documents: Vec<String> = (0..200)
+            .map(|i| format!("Document number {i}: the quick brown fox jumps over the lazy dog"))
+            .collect();
+        let mut builder = TokenizerBuilder::default();
+        let tokenizer = builder.build();
+
+        let batch = tokenizer.tokenize_batch(&documents);
+        assert_eq!(batch.len(), documents.len());
+        for (document, tokens) in documents.iter().zip(batch) {
+            let expected: Vec<_> = tokenizer.tokenize(document).collect();
+            assert_eq!(tokens, expected);
+        }
+
+        assert!(tokenizer.tokenize_batch::<_, str>([]).is_empty());
+    }
+
+    #[test]
+    fn tokenize_with_language_hints() {
+        let text = "Привет мир, hello world";
+        let mut builder = TokenizerBuilder::default();
+        let tokenizer = builder.build();
+
+        let languages = HashMap::from([(Script::Cyrillic, Language::Ukr)]);
+        let words: Vec<_> = tokenizer
+            .tokenize_with_language(text, &languages)
+            .filter(|t| t.is_word())
+            .map(|t| (t.script, t.language, t.language_hinted))
+            .collect();
+        assert_eq!(
+            words,
+            [
+                (Script::Cyrillic, Some(Language::Ukr), true),
+                (Script::Cyrillic, Some(Language::Ukr), true),
+                (Script::Latin, None, false),
+                (Script::Latin, None, false),
+            ]
+        );
+
+        assert!(tokenizer.tokenize(text).all(|t| !t.language_hinted));
+    }
+
+    #[test]
+    fn chinese_variant() {
+        let allow_list = [Language::Cmn, Language::Jpn];
+        let mut builder = TokenizerBuilder::default();
+        builder.allow_list(&allow_list);
+        let tokenizer = builder.build();
+
+        let variants = |text: &str| -> Vec<_> {
+            let tokens: Vec<_> = tokenizer.tokenize(text).collect();
+            assert!(tokens.iter().all(|t| t.language.is_none_or(|l| allow_list.contains(&l))));
+            tokens.iter().map(|t| t.chinese_variant).collect()
+        };
+
+        assert!(variants("我们在东京学习中文")
+            .iter()
+            .all(|v| *v == Some(ChineseVariant::Simplified)));
+        assert!(variants("我們在東京學習中文")
+            .iter()
+            .all(|v| *v == Some(ChineseVariant::Traditional)));
+        // ideographs shared by both variants.
+        assert!(variants("生而自由").iter().all(Option::is_none));
+        assert!(variants("hello world").iter().all(Option::is_none));
+        // the variant isn't detected on the Japanese text.
+        assert!(variants("東京で學習します").iter().all(Option::is_none));
+        assert!("東京で學習します".tokenize().all(|t| t.chinese_variant.is_none()));
+        assert!("我們在東京學習中文"
+            .tokenize()
+            .all(|t| t.chinese_variant == Some(ChineseVariant::Traditional)));
+    }
+
+    #[test]
+    fn language_hints_everywhere() {
+        let mut synonyms = SynonymExpander::new();
+        synonyms.insert("car", "automobile");
+        let expanders: Vec<Box<dyn Expander>> = vec![Box::new(synonyms)];
+        let mut builder = TokenizerBuilder::default();
+        builder.expanders(&expanders);
+        let tokenizer = builder.build();
+
+        let languages = HashMap::from([(Script::Latin, Language::Eng)]);
+        let hinted = |token: &Token| token.language == Some(Language::Eng) && token.language_hinted;
+
+        let reader = tokenizer.tokenize_reader_with_language("a car. a bus".as_bytes(), &languages);
+        let tokens: Vec<_> = reader.collect::<io::Result<_>>().unwrap();
+        assert!(!tokens.is_empty() && tokens.iter().all(hinted));
+
+        let batch = tokenizer.tokenize_batch_with_language(["a car", "a bus"], &languages);
+        assert!(batch.iter().flatten().all(hinted));
+
+        let tokens: Vec<_> =
+            tokenizer.tokenize_expanded_with_language("a car", &languages).collect();
+        assert!(tokens.iter().any(|t| t.lemma() == "automobile"));
+        assert!(tokens.iter().all(hinted));
+    }
+
+    #[cfg(feature = "stemming")]
+    #[test]
+    fn stemming() {
+        let text = "The cats are running";
+        let allow_list = [Language::Eng];
+        let mut builder = TokenizerBuilder::default();
+        builder.allow_list(&allow_list);
+        let tokenizer = builder.build();
+        let words: Vec<_> = tokenizer
+            .tokenize(text)
+            .filter(|t| t.is_word())
+            .map(|t| t.lemma().to_string())
+            .collect();
+        assert_eq!(words, ["the", "cats", "are", "running"]);
+
+        builder.stemming(true);
+        let tokenizer = builder.build();
+        let words: Vec<_> = tokenizer
+            .tokenize(text)
+            .filter(|t| t.is_word())
+            .map(|t| t.lemma().to_string())
+            .collect();
+        assert_eq!(words, ["the", "cat", "are", "run"]);
+    }
+
+    #[cfg(feature = "stemming")]
+    #[test]
+    fn stemming_single_language() {
+        let allow_list = [Language::Rus];
+        let mut builder = TokenizerBuilder::default();
+        builder.allow_list(&allow_list).stemming(true);
+        let tokenizer = builder.build();
+
+        let token = tokenizer.tokenize("книги").next().unwrap();
+        assert_eq!(token.language, Some(Language::Rus));
+        assert_eq!(token.lemma(), "книг");
+    }
+
+    #[test]
+    fn korean_jamo() {
+        let mut builder = TokenizerBuilder::default();
+        builder.create_char_map(true).korean_jamo(KoreanJamoMode::Initials);
+        let tokenizer = builder.build();
+
+        let token = tokenizer.tokenize("한국").next().unwrap();
+        assert_eq!(token.lemma(), "\u{1112}\u{1100}");
+        assert_eq!(token.char_map, Some(vec![(3, 3), (3, 3)]));
+        assert_eq!(token.original_lengths(token.lemma().len()), (2, 6));
+
+        let mut builder = TokenizerBuilder::default();
+        builder.korean_jamo(KoreanJamoMode::Jamo);
+        let tokenizer = builder.build();
+        let word = tokenizer.tokenize("하고").next().unwrap();
+        let prefix = tokenizer.tokenize("학").next().unwrap();
+        assert!(word.lemma().starts_with(prefix.lemma()));
+    }
+
+    #[cfg(feature = "japanese")]
+    #[test]
+    fn japanese_stop_parts_of_speech() {
+        use crate::normalizer::JAPANESE_STOP_PARTS_OF_SPEECH;
+
+        let mut builder = TokenizerBuilder::default();
+        builder.stop_parts_of_speech(JAPANESE_STOP_PARTS_OF_SPEECH);
+        let tokenizer = builder.build();
+
+        let stop_words: Vec<_> = tokenizer
+            .tokenize("猫が魚を食べた。")
+            .filter(|t| t.is_stopword())
+            .map(|t| t.lemma().to_string())
+            .collect();
+        assert_eq!(stop_words, ["が", "を", "た"]);
+    }
+
+    #[cfg(feature = "chinese-segmentation")]
+    #[test]
+    fn chinese_user_words() {
+        use crate::segmenter::ChineseUserWord;
+
+        let text = "像是哼着小曲的少年";
+        let mut builder = TokenizerBuilder::default();
+        builder.chinese_user_words([ChineseUserWord {
+            word: "哼着",
+            freq: Some(100_000),
+            tag: None,
+        }]);
+        let tokenizer = builder.build();
+        assert!(tokenizer.segment_str(text).any(|word| word == "哼着"));
+
+        // other tokenizers keep using the default dictionary.
+        let tokenizer = TokenizerBuilder::default().into_tokenizer();
+        assert!(tokenizer.segment_str(text).all(|word| word != "哼着"));
+    }
+
+    #[cfg(feature = "chinese-segmentation")]
+    #[test]
+    fn chinese_full_segmentation() {
+        use crate::segmenter::ChineseSegmentationMode;
+
+        let mut builder = TokenizerBuilder::default();
+        builder.chinese_segmentation_mode(ChineseSegmentationMode::Full);
+        let tokenizer = builder.build();
+
+        let tokens: Vec<_> = tokenizer
+            .segment("我来到北京清华大学了")
+            .map(|t| {
+                (
+                    t.lemma().to_string(),
+                    t.char_start,
+                    t.byte_start,
+                    t.position_increment,
+                    t.position_length,
+                )
+            })
+            .collect();
+        assert_eq!(
+            tokens,
+            [
+                ("我".to_string(), 0, 0, 1, 1),
+                ("来到".to_string(), 1, 3, 1, 1),
+                ("北京".to_string(), 3, 9, 1, 1),
+                ("清华".to_string(), 5, 15, 1, 2),
+                ("清华大学".to_string(), 5, 15, 0, 4),
+                ("华大".to_string(), 6, 18, 1, 2),
+                ("大学".to_string(), 7, 21, 1, 2),
+                // starts at the end of "清华大学".
+                ("了".to_string(), 9, 27, 2, 1),
+            ]
+        );
+    }
+
     #[quickcheck]
     fn shorten_after_tokenized(text: String) -> bool {
         let text = text.as_str();